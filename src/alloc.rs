@@ -0,0 +1,149 @@
+//! Reporting Rust heap allocations to Ruby's garbage collector.
+//!
+//! Ruby schedules garbage collections based on how much memory was `malloc`ed
+//! since the last run. Memory allocated by Rust code is invisible to it, so a
+//! Ruby object wrapping a large Rust structure may never trigger a collection.
+//!
+//! `RubyTrackingAllocator` wraps another global allocator and reports the
+//! difference to Ruby via `rb_gc_adjust_memory_usage`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    sync::atomic::{AtomicBool, AtomicIsize, Ordering},
+};
+
+use crate::binding::{gc, thread};
+
+/// A global allocator which reports allocations to Ruby's GC
+///
+/// Allocation deltas are accumulated in an atomic counter and only reported
+/// once they exceed `threshold` bytes. Reporting happens on the allocating
+/// thread, and only if that thread holds the GVL and no garbage collection
+/// is running, so the allocator can be used from any thread, including native
+/// threads unknown to Ruby. Deltas from other threads are picked up by the
+/// next allocation on a Ruby thread or by an explicit `flush`.
+///
+/// # Examples
+///
+/// ```
+/// use std::alloc::System;
+///
+/// use rutie::{alloc::RubyTrackingAllocator, VM};
+///
+/// #[global_allocator]
+/// static GLOBAL: RubyTrackingAllocator<System> = RubyTrackingAllocator::new(System);
+///
+/// # VM::init();
+/// let buffer: Vec<u8> = Vec::with_capacity(1024 * 1024);
+///
+/// GLOBAL.flush();
+///
+/// assert_eq!(GLOBAL.pending(), 0);
+/// # drop(buffer);
+/// ```
+pub struct RubyTrackingAllocator<A> {
+    inner: A,
+    threshold: usize,
+    pending: AtomicIsize,
+    flushing: AtomicBool,
+}
+
+impl<A> RubyTrackingAllocator<A> {
+    /// Number of bytes which are accumulated before they are reported by default
+    pub const DEFAULT_THRESHOLD: usize = 64 * 1024;
+
+    /// Wraps `inner`, reporting allocations in batches of `DEFAULT_THRESHOLD` bytes
+    pub const fn new(inner: A) -> Self {
+        Self::with_threshold(inner, Self::DEFAULT_THRESHOLD)
+    }
+
+    /// Wraps `inner`, reporting allocations in batches of `threshold` bytes
+    pub const fn with_threshold(inner: A, threshold: usize) -> Self {
+        RubyTrackingAllocator {
+            inner,
+            threshold,
+            pending: AtomicIsize::new(0),
+            flushing: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the number of bytes which were not yet reported to Ruby
+    ///
+    /// Negative values mean more memory was freed than allocated.
+    pub fn pending(&self) -> isize {
+        self.pending.load(Ordering::Relaxed)
+    }
+
+    /// Reports all pending bytes to Ruby
+    ///
+    /// Does nothing unless the current thread holds the GVL and no garbage
+    /// collection is running. Returns `true` if the pending bytes were reported.
+    pub fn flush(&self) -> bool {
+        // `has_gvl` must be checked first, `rb_during_gc` is not safe to call
+        // before the VM is initialized.
+        if !thread::has_gvl() || gc::is_during_gc() {
+            return false;
+        }
+
+        // Adjusting memory usage may start a GC, which can free Rust values
+        // and re-enter the allocator.
+        if self.flushing.swap(true, Ordering::Acquire) {
+            return false;
+        }
+
+        let delta = self.pending.swap(0, Ordering::Relaxed);
+
+        if delta != 0 {
+            gc::adjust_memory_usage(delta);
+        }
+
+        self.flushing.store(false, Ordering::Release);
+
+        true
+    }
+
+    fn record(&self, delta: isize) {
+        let pending = self.pending.fetch_add(delta, Ordering::Relaxed) + delta;
+
+        if pending.unsigned_abs() >= self.threshold {
+            self.flush();
+        }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for RubyTrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+
+        if !ptr.is_null() {
+            self.record(layout.size() as isize);
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            self.record(layout.size() as isize);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        self.record(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+
+        if !new_ptr.is_null() {
+            self.record(new_size as isize - layout.size() as isize);
+        }
+
+        new_ptr
+    }
+}
@@ -15,6 +15,15 @@ pub fn entry(array: Value, offset: i64) -> Value {
     unsafe { array::rb_ary_entry(array, offset as c_long) }
 }
 
+// The caller has to check that `offset` is within the length of the array.
+pub unsafe fn entry_unchecked(array: Value, offset: usize) -> Value {
+    *array::rb_ary_const_ptr(array).add(offset)
+}
+
+pub fn const_ptr(array: Value) -> *const Value {
+    unsafe { array::rb_ary_const_ptr(array) }
+}
+
 pub fn join(array: Value, separator: Value) -> Value {
     unsafe { array::rb_ary_join(array, separator) }
 }
@@ -67,3 +76,23 @@ pub fn sort(array: Value) -> Value {
 pub fn sort_bang(array: Value) -> Value {
     unsafe { array::rb_ary_sort_bang(array) }
 }
+
+pub fn clear(array: Value) -> Value {
+    unsafe { array::rb_ary_clear(array) }
+}
+
+pub fn delete_at(array: Value, offset: i64) -> Value {
+    unsafe { array::rb_ary_delete_at(array, offset as c_long) }
+}
+
+pub fn includes(array: Value, item: Value) -> bool {
+    unsafe { array::rb_ary_includes(array, item).is_true() }
+}
+
+pub fn replace(array: Value, other_array: Value) -> Value {
+    unsafe { array::rb_ary_replace(array, other_array) }
+}
+
+pub fn subseq(array: Value, start: i64, length: i64) -> Value {
+    unsafe { array::rb_ary_subseq(array, start as c_long, length as c_long) }
+}
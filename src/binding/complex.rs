@@ -0,0 +1,13 @@
+use crate::{rubysys::complex, types::Value};
+
+pub fn new(real: Value, imag: Value) -> Value {
+    unsafe { complex::rb_complex_new(real, imag) }
+}
+
+pub fn real(complex: Value) -> Value {
+    unsafe { complex::rb_complex_real(complex) }
+}
+
+pub fn imaginary(complex: Value) -> Value {
+    unsafe { complex::rb_complex_imag(complex) }
+}
@@ -0,0 +1,244 @@
+use std::{
+    mem, ptr,
+    sync::atomic::{AtomicPtr, AtomicU8, AtomicUsize, Ordering},
+};
+
+use crate::{
+    rubysys::debug::{self, PostponedJobFunction},
+    types::{c_int, c_void, Value},
+};
+
+#[cfg(ruby_gte_3_3)]
+use crate::rubysys::debug::{PostponedJobHandle, POSTPONED_JOB_HANDLE_INVALID};
+#[cfg(ruby_gte_3_3)]
+use std::sync::atomic::AtomicBool;
+
+// Ruby identifies postponed jobs by their C function alone: before 3.3 a
+// second job with the same function is dropped, from 3.3 on its data replaces
+// the first job's. Every Rust job therefore goes through a single
+// `dispatch_postponed_jobs` function which runs the jobs pending in this
+// fixed table, so registering never allocates.
+const POSTPONED_JOB_SLOTS: usize = 64;
+
+const SLOT_FREE: u8 = 0;
+const SLOT_BUSY: u8 = 1;
+const SLOT_ONE_SHOT: u8 = 2;
+#[cfg(ruby_gte_3_3)]
+const SLOT_PREREGISTERED: u8 = 3;
+
+struct PostponedJobSlot {
+    state: AtomicU8,
+    call: AtomicUsize,
+    data: AtomicPtr<c_void>,
+    #[cfg(ruby_gte_3_3)]
+    triggered: AtomicBool,
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_SLOT: PostponedJobSlot = PostponedJobSlot {
+    state: AtomicU8::new(SLOT_FREE),
+    call: AtomicUsize::new(0),
+    data: AtomicPtr::new(ptr::null_mut()),
+    #[cfg(ruby_gte_3_3)]
+    triggered: AtomicBool::new(false),
+};
+
+static POSTPONED_JOBS: [PostponedJobSlot; POSTPONED_JOB_SLOTS] = [EMPTY_SLOT; POSTPONED_JOB_SLOTS];
+
+extern "C" fn call_postponed_job<F>(data: *mut c_void)
+where
+    F: Fn() + Sync,
+{
+    let job = unsafe { &*(data as *const F) };
+
+    job();
+}
+
+extern "C" fn dispatch_postponed_jobs(_data: *mut c_void) {
+    for slot in POSTPONED_JOBS.iter() {
+        match slot.state.load(Ordering::Acquire) {
+            SLOT_ONE_SHOT => {
+                if slot
+                    .state
+                    .compare_exchange(
+                        SLOT_ONE_SHOT,
+                        SLOT_BUSY,
+                        Ordering::Acquire,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+                {
+                    let (call, data) = slot_job(slot);
+
+                    // Free the slot first, so the job may register itself again
+                    slot.state.store(SLOT_FREE, Ordering::Release);
+
+                    call(data);
+                }
+            }
+            #[cfg(ruby_gte_3_3)]
+            SLOT_PREREGISTERED => {
+                if slot.triggered.swap(false, Ordering::AcqRel) {
+                    let (call, data) = slot_job(slot);
+
+                    call(data);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn slot_job(slot: &PostponedJobSlot) -> (PostponedJobFunction, *mut c_void) {
+    let call = slot.call.load(Ordering::Relaxed);
+    let call = unsafe { mem::transmute::<usize, PostponedJobFunction>(call) };
+
+    (call, slot.data.load(Ordering::Relaxed))
+}
+
+// Returns the index of a slot in `state` holding `job`, or claims a free slot for it.
+// The flag is `true` if the slot was claimed by this call.
+fn claim_postponed_job_slot<F>(job: &'static F, state: u8) -> Option<(usize, bool)>
+where
+    F: Fn() + Sync,
+{
+    let call = call_postponed_job::<F> as PostponedJobFunction as usize;
+    let data = job as *const F as *mut c_void;
+
+    let existing = POSTPONED_JOBS.iter().position(|slot| {
+        slot.state.load(Ordering::Acquire) == state
+            && slot.call.load(Ordering::Relaxed) == call
+            && slot.data.load(Ordering::Relaxed) == data
+    });
+
+    if let Some(index) = existing {
+        return Some((index, false));
+    }
+
+    let index = POSTPONED_JOBS.iter().position(|slot| {
+        slot.state
+            .compare_exchange(SLOT_FREE, SLOT_BUSY, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    })?;
+
+    let slot = &POSTPONED_JOBS[index];
+
+    slot.call.store(call, Ordering::Relaxed);
+    slot.data.store(data, Ordering::Relaxed);
+    slot.state.store(state, Ordering::Release);
+
+    Some((index, true))
+}
+
+// Does not allocate, so it can be called from a signal handler
+pub fn register_postponed_job<F>(job: &'static F) -> bool
+where
+    F: Fn() + Sync,
+{
+    let (index, claimed) = match claim_postponed_job_slot(job, SLOT_ONE_SHOT) {
+        Some(slot) => slot,
+        None => return false,
+    };
+
+    let result = unsafe {
+        debug::rb_postponed_job_register_one(0, dispatch_postponed_jobs, ptr::null_mut())
+    };
+
+    if result == 0 {
+        // Ruby's queue is full, give the slot back unless the job already ran or
+        // an earlier registration of it is still pending
+        if !claimed {
+            return false;
+        }
+
+        let _ = POSTPONED_JOBS[index].state.compare_exchange(
+            SLOT_ONE_SHOT,
+            SLOT_FREE,
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        );
+
+        return false;
+    }
+
+    true
+}
+
+#[cfg(ruby_gte_3_3)]
+pub fn preregister_postponed_job<F>(job: &'static F) -> Option<(usize, PostponedJobHandle)>
+where
+    F: Fn() + Sync,
+{
+    // Preregistering the same function again returns the same handle
+    let handle =
+        unsafe { debug::rb_postponed_job_preregister(0, dispatch_postponed_jobs, ptr::null_mut()) };
+
+    if handle == POSTPONED_JOB_HANDLE_INVALID {
+        return None;
+    }
+
+    claim_postponed_job_slot(job, SLOT_PREREGISTERED).map(|(index, _)| (index, handle))
+}
+
+#[cfg(ruby_gte_3_3)]
+pub fn trigger_postponed_job(index: usize, handle: PostponedJobHandle) {
+    POSTPONED_JOBS[index]
+        .triggered
+        .store(true, Ordering::Release);
+
+    unsafe { debug::rb_postponed_job_trigger(handle) };
+}
+
+pub fn profile_frames(start: usize, limit: usize) -> Vec<(Value, i32)> {
+    let mut frames = vec![Value::from(0); limit];
+    let mut lines: Vec<c_int> = vec![0; limit];
+
+    let count = unsafe {
+        debug::rb_profile_frames(
+            start as c_int,
+            limit as c_int,
+            frames.as_mut_ptr(),
+            lines.as_mut_ptr(),
+        )
+    };
+
+    frames.into_iter().zip(lines).take(count as usize).collect()
+}
+
+#[cfg(ruby_gte_3_2)]
+pub fn profile_thread_frames(thread: Value, start: usize, limit: usize) -> Vec<(Value, i32)> {
+    let mut frames = vec![Value::from(0); limit];
+    let mut lines: Vec<c_int> = vec![0; limit];
+
+    let count = unsafe {
+        debug::rb_profile_thread_frames(
+            thread,
+            start as c_int,
+            limit as c_int,
+            frames.as_mut_ptr(),
+            lines.as_mut_ptr(),
+        )
+    };
+
+    frames.into_iter().zip(lines).take(count as usize).collect()
+}
+
+pub fn frame_classpath(frame: Value) -> Value {
+    unsafe { debug::rb_profile_frame_classpath(frame) }
+}
+
+pub fn frame_full_label(frame: Value) -> Value {
+    unsafe { debug::rb_profile_frame_full_label(frame) }
+}
+
+pub fn frame_label(frame: Value) -> Value {
+    unsafe { debug::rb_profile_frame_label(frame) }
+}
+
+pub fn frame_method_name(frame: Value) -> Value {
+    unsafe { debug::rb_profile_frame_method_name(frame) }
+}
+
+pub fn frame_path(frame: Value) -> Value {
+    unsafe { debug::rb_profile_frame_path(frame) }
+}
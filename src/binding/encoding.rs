@@ -1,5 +1,5 @@
 use crate::{
-    rubysys::encoding,
+    rubysys::encoding::{self, EconvPtr},
     types::{c_char, c_int, EncodingIndex, Value, ValueType},
 };
 use std::ffi::CString;
@@ -33,6 +33,14 @@ pub fn from_encoding_index(idx: EncodingIndex) -> Value {
     unsafe { encoding::rb_enc_from_encoding(encoding::rb_enc_from_index(idx)) }
 }
 
+pub fn filesystem_encoding() -> Value {
+    unsafe { from_encoding_index(encoding::rb_filesystem_encindex()) }
+}
+
+pub fn locale_encoding() -> Value {
+    unsafe { from_encoding_index(encoding::rb_locale_encindex()) }
+}
+
 pub fn usascii_encoding() -> Value {
     unsafe { from_encoding_index(encoding::rb_usascii_encindex()) }
 }
@@ -54,10 +62,74 @@ pub fn encode(str: Value, to: Value, ecflags: c_int, ecopts: Value) -> Value {
     unsafe { encoding::rb_str_encode(str, to, ecflags, ecopts) }
 }
 
+pub fn export_to_enc(str: Value, enc: Value) -> Value {
+    unsafe { encoding::rb_str_export_to_enc(str, encoding::rb_to_encoding(enc)) }
+}
+
 pub fn econv_prepare_opts(opthash: Value, opts: *mut Value) -> c_int {
     unsafe { encoding::rb_econv_prepare_opts(opthash, opts as *mut _) }
 }
 
+// Returns a null pointer when there is no converter between the encodings
+pub fn econv_open(source: &str, destination: &str, ecflags: c_int) -> EconvPtr {
+    let source = CString::new(source).unwrap();
+    let destination = CString::new(destination).unwrap();
+
+    unsafe { encoding::rb_econv_open(source.as_ptr(), destination.as_ptr(), ecflags) }
+}
+
+// Returns the `rb_econv_result_t` with the number of bytes read from `input` and
+// written to `output`
+pub fn econv_convert(
+    ec: EconvPtr,
+    input: &[u8],
+    output: &mut [u8],
+    flags: c_int,
+) -> (c_int, usize, usize) {
+    let mut input_ptr = input.as_ptr();
+    let mut output_ptr = output.as_mut_ptr();
+
+    let result = unsafe {
+        encoding::rb_econv_convert(
+            ec,
+            &mut input_ptr,
+            input.as_ptr().add(input.len()),
+            &mut output_ptr,
+            output.as_mut_ptr().add(output.len()),
+            flags,
+        )
+    };
+
+    let read = input_ptr as usize - input.as_ptr() as usize;
+    let written = output_ptr as usize - output.as_ptr() as usize;
+
+    (result, read, written)
+}
+
+pub fn econv_set_replacement(ec: EconvPtr, replacement: &[u8], encoding: &str) -> bool {
+    let encoding = CString::new(encoding).unwrap();
+
+    let result = unsafe {
+        encoding::rb_econv_set_replacement(
+            ec,
+            replacement.as_ptr(),
+            replacement.len(),
+            encoding.as_ptr(),
+        )
+    };
+
+    result == 0
+}
+
+// Returns an exception for the last error of the converter or nil
+pub fn econv_make_exception(ec: EconvPtr) -> Value {
+    unsafe { encoding::rb_econv_make_exception(ec) }
+}
+
+pub fn econv_close(ec: EconvPtr) {
+    unsafe { encoding::rb_econv_close(ec) }
+}
+
 // ptr - pointer for current point in string starting from the beginning
 // end - pointer for the end of the string
 // len_p - a mutable integer pointer for Ruby to give us how much we need to add on to `ptr`
@@ -0,0 +1,18 @@
+use crate::{
+    binding::symbol,
+    rubysys::enumerator::{self, EnumeratorSizeFunction},
+    types::Value,
+    util,
+};
+
+pub fn enumeratorize_with_size(
+    object: Value,
+    method: &str,
+    arguments: &[Value],
+    size_fn: Option<EnumeratorSizeFunction>,
+) -> Value {
+    let method = symbol::id_to_sym(symbol::internal_id(method));
+    let (argc, argv) = util::process_arguments(arguments);
+
+    unsafe { enumerator::rb_enumeratorize_with_size(object, method, argc, argv, size_fn) }
+}
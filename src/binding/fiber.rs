@@ -0,0 +1,64 @@
+use crate::{
+    rubysys::fiber::{self, BlockCallFunction},
+    types::Value,
+    util,
+};
+
+pub fn new(func: BlockCallFunction, callback_obj: Value) -> Value {
+    unsafe { fiber::rb_fiber_new(func, callback_obj) }
+}
+
+pub fn current() -> Value {
+    unsafe { fiber::rb_fiber_current() }
+}
+
+pub fn is_alive(fiber: Value) -> bool {
+    unsafe { fiber::rb_fiber_alive_p(fiber) }.is_true()
+}
+
+pub fn resume(fiber: Value, arguments: &[Value]) -> Value {
+    let (argc, argv) = util::process_arguments(arguments);
+
+    unsafe { fiber::rb_fiber_resume(fiber, argc, argv) }
+}
+
+pub fn yield_values(arguments: &[Value]) -> Value {
+    let (argc, argv) = util::process_arguments(arguments);
+
+    unsafe { fiber::rb_fiber_yield(argc, argv) }
+}
+
+#[cfg(ruby_gte_3_1)]
+pub fn scheduler_current() -> Value {
+    unsafe { fiber::rb_fiber_scheduler_current() }
+}
+
+#[cfg(ruby_gte_3_1)]
+pub fn scheduler_io_wait(scheduler: Value, io: Value, events: Value, timeout: Value) -> Value {
+    unsafe { fiber::rb_fiber_scheduler_io_wait(scheduler, io, events, timeout) }
+}
+
+#[cfg(ruby_gte_3_1)]
+pub fn scheduler_io_wait_readable(scheduler: Value, io: Value) -> Value {
+    unsafe { fiber::rb_fiber_scheduler_io_wait_readable(scheduler, io) }
+}
+
+#[cfg(ruby_gte_3_1)]
+pub fn scheduler_io_wait_writable(scheduler: Value, io: Value) -> Value {
+    unsafe { fiber::rb_fiber_scheduler_io_wait_writable(scheduler, io) }
+}
+
+#[cfg(ruby_gte_3_1)]
+pub fn scheduler_kernel_sleep(scheduler: Value, duration: Value) -> Value {
+    unsafe { fiber::rb_fiber_scheduler_kernel_sleep(scheduler, duration) }
+}
+
+#[cfg(ruby_gte_3_1)]
+pub fn scheduler_block(scheduler: Value, blocker: Value, timeout: Value) -> Value {
+    unsafe { fiber::rb_fiber_scheduler_block(scheduler, blocker, timeout) }
+}
+
+#[cfg(ruby_gte_3_1)]
+pub fn scheduler_unblock(scheduler: Value, blocker: Value, fiber: Value) -> Value {
+    unsafe { fiber::rb_fiber_scheduler_unblock(scheduler, blocker, fiber) }
+}
@@ -1,4 +1,8 @@
-use crate::{rubysys::gc, types::Value, util};
+use crate::{
+    rubysys::gc,
+    types::{CallbackPtr, Value},
+    util,
+};
 
 pub fn adjust_memory_usage(diff: isize) {
     unsafe { gc::rb_gc_adjust_memory_usage(diff) };
@@ -16,6 +20,12 @@ pub fn enable() -> Value {
     unsafe { gc::rb_gc_enable() }
 }
 
+pub fn is_during_gc() -> bool {
+    let int = unsafe { gc::rb_during_gc() };
+
+    util::c_int_to_bool(int)
+}
+
 pub fn force_recycle(obj: Value) {
     unsafe { gc::rb_gc_force_recycle(obj) }
 }
@@ -34,6 +44,10 @@ pub fn register(obj: Value) {
     unsafe { gc::rb_gc_register_address(addr) }
 }
 
+pub fn register_address(addr: *const Value) {
+    unsafe { gc::rb_gc_register_address(addr as CallbackPtr) }
+}
+
 pub fn register_mark(obj: Value) {
     unsafe { gc::rb_gc_register_mark_object(obj) }
 }
@@ -52,6 +66,10 @@ pub fn unregister(obj: Value) {
     unsafe { gc::rb_gc_unregister_address(addr) }
 }
 
+pub fn unregister_address(addr: *const Value) {
+    unsafe { gc::rb_gc_unregister_address(addr as CallbackPtr) }
+}
+
 pub unsafe fn is_marked(obj: Value) -> bool {
     let int = gc::rb_objspace_marked_object_p(obj);
 
@@ -1,3 +1,5 @@
+use std::ops::ControlFlow;
+
 use crate::{
     binding::fixnum,
     rubysys::hash,
@@ -37,13 +39,17 @@ pub fn length(hash: Value) -> i64 {
     }
 }
 
+pub fn lookup(hash: Value, key: Value, default: Value) -> Value {
+    unsafe { hash::rb_hash_lookup2(hash, key, default) }
+}
+
 use crate::util::callback_call::hash_foreach_callback as each_callback;
 
-pub fn each<F>(hash: Value, closure_callback: F)
+pub fn each<F>(hash: Value, mut closure_callback: F)
 where
-    F: FnMut(AnyObject, AnyObject),
+    F: FnMut(AnyObject, AnyObject) -> ControlFlow<()>,
 {
-    let closure_ptr = &closure_callback as *const _ as CallbackMutPtr;
+    let closure_ptr = &mut closure_callback as *mut _ as CallbackMutPtr;
 
     unsafe {
         hash::rb_hash_foreach(
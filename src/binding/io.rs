@@ -0,0 +1,15 @@
+use crate::{rubysys::io, types::Value};
+
+// Returns IO Value or NilClass Value
+// same as method `IO.try_convert`
+pub fn check_io(io: Value) -> Value {
+    unsafe { io::rb_io_check_io(io) }
+}
+
+pub fn flush(io: Value) -> Value {
+    unsafe { io::rb_io_flush(io) }
+}
+
+pub fn write(io: Value, string: Value) -> Value {
+    unsafe { io::rb_io_write(io, string) }
+}
@@ -0,0 +1,19 @@
+use crate::{binding::symbol, rubysys::method, types::Value, util};
+
+pub fn object_method(object: Value, name: &str) -> Value {
+    let name = symbol::id_to_sym(symbol::internal_id(name));
+
+    unsafe { method::rb_obj_method(object, name) }
+}
+
+pub fn call(method: Value, arguments: &[Value]) -> Value {
+    let (argc, argv) = util::process_arguments(arguments);
+
+    unsafe { method::rb_method_call(argc, argv, method) }
+}
+
+pub fn call_with_block(method: Value, arguments: &[Value], block: Value) -> Value {
+    let (argc, argv) = util::process_arguments(arguments);
+
+    unsafe { method::rb_method_call_with_block(argc, argv, method, block) }
+}
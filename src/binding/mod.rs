@@ -1,14 +1,28 @@
 pub mod array;
 pub mod class;
+pub mod complex;
+pub mod debug;
 pub mod encoding;
+pub mod enumerator;
+pub mod fiber;
 pub mod fixnum;
 pub mod float;
 pub mod gc;
 pub mod global;
 pub mod hash;
+pub mod io;
+pub mod method;
 pub mod module;
+pub mod mutex;
+#[cfg(ruby_gte_3_0)]
+pub mod ractor;
+pub mod range;
+pub mod rational;
+pub mod regexp;
 pub mod rproc;
 pub mod string;
 pub mod symbol;
 pub mod thread;
+pub mod time;
+pub mod tracepoint;
 pub mod vm;
@@ -0,0 +1,49 @@
+use crate::{
+    rubysys::mutex,
+    types::{InternalValue, Value},
+    NilClass, Object,
+};
+
+pub fn new() -> Value {
+    unsafe { mutex::rb_mutex_new() }
+}
+
+pub fn lock(mutex: Value) -> Value {
+    unsafe { mutex::rb_mutex_lock(mutex) }
+}
+
+pub fn try_lock(mutex: Value) -> bool {
+    unsafe { mutex::rb_mutex_trylock(mutex) }.is_true()
+}
+
+pub fn unlock(mutex: Value) -> Value {
+    unsafe { mutex::rb_mutex_unlock(mutex) }
+}
+
+pub fn is_locked(mutex: Value) -> bool {
+    unsafe { mutex::rb_mutex_locked_p(mutex) }.is_true()
+}
+
+pub fn sleep(mutex: Value, timeout: Value) -> Value {
+    unsafe { mutex::rb_mutex_sleep(mutex, timeout) }
+}
+
+pub fn synchronize<F>(mutex: Value, mut func: F)
+where
+    F: FnMut(),
+{
+    let data = Value::from(&mut func as *mut F as InternalValue);
+
+    unsafe { mutex::rb_mutex_synchronize(mutex, synchronize_callback::<F>, data) };
+}
+
+extern "C" fn synchronize_callback<F>(data: Value) -> Value
+where
+    F: FnMut(),
+{
+    let func = unsafe { &mut *(data.value as *mut F) };
+
+    func();
+
+    NilClass::new().value()
+}
@@ -0,0 +1,34 @@
+use crate::{
+    rubysys::ractor::{self, RactorLocalKeyPtr},
+    types::Value,
+};
+
+pub fn ext_ractor_safe(flag: bool) {
+    unsafe { ractor::rb_ext_ractor_safe(flag) };
+}
+
+pub fn make_shareable(object: Value) -> Value {
+    unsafe { ractor::rb_ractor_make_shareable(object) }
+}
+
+pub fn make_shareable_copy(object: Value) -> Value {
+    unsafe { ractor::rb_ractor_make_shareable_copy(object) }
+}
+
+pub fn local_storage_new_key() -> RactorLocalKeyPtr {
+    unsafe { ractor::rb_ractor_local_storage_value_newkey() }
+}
+
+pub fn local_storage_get(key: RactorLocalKeyPtr) -> Option<Value> {
+    let mut value = Value::from(0);
+
+    if unsafe { ractor::rb_ractor_local_storage_value_lookup(key, &mut value) } {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+pub fn local_storage_set(key: RactorLocalKeyPtr, value: Value) {
+    unsafe { ractor::rb_ractor_local_storage_value_set(key, value) };
+}
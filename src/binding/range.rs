@@ -0,0 +1,37 @@
+use crate::{
+    binding::global::RubySpecialConsts,
+    rubysys::range,
+    types::{c_int, c_long, InternalValue, Value},
+    util,
+};
+
+pub fn new(beg: Value, end: Value, exclusive: bool) -> Value {
+    unsafe { range::rb_range_new(beg, end, util::bool_to_c_int(exclusive)) }
+}
+
+// Returns `(begin, end, exclusive)` of the range.
+pub fn values(range: Value) -> (Value, Value, bool) {
+    let nil = Value::from(RubySpecialConsts::Nil as InternalValue);
+    let mut beg = nil;
+    let mut end = nil;
+    let mut excl: c_int = 0;
+
+    unsafe { range::rb_range_values(range, &mut beg, &mut end, &mut excl) };
+
+    (beg, end, util::c_int_to_bool(excl))
+}
+
+// Resolves the range against a sequence of `len` elements following Ruby's indexing rules.
+//
+// Returns `nil` when the range is out of bounds, otherwise `begp` and `lenp` are set.
+pub fn beg_len(range: Value, begp: &mut i64, lenp: &mut i64, len: i64) -> Value {
+    let mut beg: c_long = 0;
+    let mut rlen: c_long = 0;
+
+    let result = unsafe { range::rb_range_beg_len(range, &mut beg, &mut rlen, len as c_long, 0) };
+
+    *begp = beg as i64;
+    *lenp = rlen as i64;
+
+    result
+}
@@ -0,0 +1,13 @@
+use crate::{rubysys::rational, types::Value};
+
+pub fn new(num: Value, den: Value) -> Value {
+    unsafe { rational::rb_rational_new(num, den) }
+}
+
+pub fn numerator(rational: Value) -> Value {
+    unsafe { rational::rb_rational_num(rational) }
+}
+
+pub fn denominator(rational: Value) -> Value {
+    unsafe { rational::rb_rational_den(rational) }
+}
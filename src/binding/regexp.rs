@@ -0,0 +1,28 @@
+use crate::{
+    rubysys::regexp,
+    types::{c_int, Value},
+};
+
+pub fn new(pattern: Value, options: i32) -> Value {
+    unsafe { regexp::rb_reg_new_str(pattern, options as c_int) }
+}
+
+pub fn options(regexp: Value) -> i32 {
+    unsafe { regexp::rb_reg_options(regexp) as i32 }
+}
+
+pub fn quote(string: Value) -> Value {
+    unsafe { regexp::rb_reg_quote(string) }
+}
+
+pub fn nth_match(nth: i32, match_data: Value) -> Value {
+    unsafe { regexp::rb_reg_nth_match(nth as c_int, match_data) }
+}
+
+pub fn pre_match(match_data: Value) -> Value {
+    unsafe { regexp::rb_reg_match_pre(match_data) }
+}
+
+pub fn post_match(match_data: Value) -> Value {
+    unsafe { regexp::rb_reg_match_post(match_data) }
+}
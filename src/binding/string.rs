@@ -18,6 +18,21 @@ pub fn new_utf8(string: &str) -> Value {
     unsafe { string::rb_utf8_str_new(str, len) }
 }
 
+pub fn with_capacity(capacity: usize) -> Value {
+    let value = new_utf8("");
+
+    unsafe { string::rb_str_modify_expand(value, capacity as c_long) };
+
+    value
+}
+
+pub fn new_binary(bytes: &[u8]) -> Value {
+    let bts = bytes.as_ptr() as *const c_char;
+    let len = bytes.len() as c_long;
+
+    unsafe { string::rb_str_new(bts, len) }
+}
+
 pub fn new_from_bytes(bytes: &[u8], enc: Value) -> Value {
     let bts = bytes.as_ptr() as *const c_char;
     let len = bytes.len() as c_long;
@@ -29,6 +44,34 @@ pub fn new_frozen(value: Value) -> Value {
     unsafe { string::rb_str_new_frozen(value) }
 }
 
+#[cfg(ruby_gte_3_0)]
+pub fn interned(string: &str) -> Value {
+    let str = string.as_ptr() as *const c_char;
+    let len = string.len() as c_long;
+
+    unsafe {
+        let utf8 = encoding::rb_enc_from_index(encoding::rb_utf8_encindex());
+
+        string::rb_enc_interned_str(str, len, utf8)
+    }
+}
+
+#[cfg(not(ruby_gte_3_0))]
+pub fn interned(string: &str) -> Value {
+    to_interned(new_utf8(string))
+}
+
+#[cfg(ruby_gte_3_0)]
+pub fn to_interned(value: Value) -> Value {
+    unsafe { string::rb_str_to_interned_str(value) }
+}
+
+// `String#-@` deduplicates through the same table before Ruby 3.0
+#[cfg(not(ruby_gte_3_0))]
+pub fn to_interned(value: Value) -> Value {
+    crate::binding::vm::call_method(value, "-@", &[])
+}
+
 // Returns RString Value or NilClass Value
 // same as method `String.try_convert`
 pub fn method_to_str(str: Value) -> Value {
@@ -92,16 +135,55 @@ pub fn concat(value: Value, bytes: &[u8]) -> Value {
     unsafe { string::rb_str_cat(value, str, len) }
 }
 
+pub fn capacity(str: Value) -> usize {
+    unsafe { string::rb_str_capacity(str) as usize }
+}
+
+pub fn reserve(str: Value, additional: usize) {
+    unsafe { string::rb_str_modify_expand(str, additional as c_long) }
+}
+
+pub fn modify(str: Value) {
+    unsafe { string::rb_str_modify(str) }
+}
+
+pub fn set_len(str: Value, len: usize) {
+    unsafe { string::rb_str_set_len(str, len as c_long) }
+}
+
+pub fn mut_ptr(str: Value) -> *mut u8 {
+    unsafe { string::rstring_ptr(str) as *mut u8 }
+}
+
+pub fn is_ascii_only(str: Value) -> bool {
+    unsafe { string::rb_enc_str_asciionly_p(str) }
+}
+
+// Calls `to_path` on objects like `Pathname` and checks the result for null bytes
+pub fn get_path(obj: Value) -> Value {
+    unsafe { string::rb_get_path(obj) }
+}
+
+pub fn dup(str: Value) -> Value {
+    unsafe { string::rb_str_dup(str) }
+}
+
+pub fn substr(str: Value, start: i64, length: i64) -> Value {
+    unsafe { string::rb_str_substr(str, start as c_long, length as c_long) }
+}
+
+pub fn scrub(str: Value, replacement: Value) -> Value {
+    unsafe { string::rb_str_scrub(str, replacement) }
+}
+
 pub fn is_lockedtmp(str: Value) -> bool {
     unsafe { string::is_lockedtmp(str) }
 }
 
-#[allow(dead_code)]
 pub fn locktmp(str: Value) -> Value {
     unsafe { string::rb_str_locktmp(str) }
 }
 
-#[allow(dead_code)]
 pub fn unlocktmp(str: Value) -> Value {
     unsafe { string::rb_str_unlocktmp(str) }
 }
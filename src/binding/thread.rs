@@ -1,6 +1,7 @@
 use std::{mem::transmute, ptr};
 
 use crate::{
+    binding::symbol::internal_id,
     rubysys::thread,
     types::{CallbackMutPtr, CallbackPtr, Value},
     util, Object,
@@ -83,6 +84,44 @@ where
     }
 }
 
+pub fn current() -> Value {
+    unsafe { thread::rb_thread_current() }
+}
+
+pub fn main() -> Value {
+    unsafe { thread::rb_thread_main() }
+}
+
+pub fn kill(thread: Value) -> Value {
+    unsafe { thread::rb_thread_kill(thread) }
+}
+
+pub fn wakeup(thread: Value) -> Value {
+    unsafe { thread::rb_thread_wakeup(thread) }
+}
+
+pub fn schedule() {
+    unsafe { thread::rb_thread_schedule() };
+}
+
+pub fn check_ints() {
+    unsafe { thread::rb_thread_check_ints() };
+}
+
+pub fn local_aref(thread: Value, key: &str) -> Value {
+    unsafe { thread::rb_thread_local_aref(thread, internal_id(key)) }
+}
+
+pub fn local_aset(thread: Value, key: &str, value: Value) -> Value {
+    unsafe { thread::rb_thread_local_aset(thread, internal_id(key), value) }
+}
+
+pub fn has_gvl() -> bool {
+    let int = unsafe { thread::ruby_thread_has_gvl_p() };
+
+    util::c_int_to_bool(int)
+}
+
 pub fn call_with_gvl<F, R>(func: F) -> R
 where
     F: FnMut() -> R,
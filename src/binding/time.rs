@@ -0,0 +1,40 @@
+use crate::{
+    binding::fixnum,
+    rubysys::time,
+    types::{c_int, Value},
+};
+
+fn new(secs: i64, nanos: u32, offset: c_int) -> Value {
+    let ts = time::timespec {
+        tv_sec: secs as _,
+        tv_nsec: nanos as _,
+    };
+
+    unsafe { time::rb_time_timespec_new(&ts, offset) }
+}
+
+pub fn new_local(secs: i64, nanos: u32) -> Value {
+    new(secs, nanos, time::RUBY_TIME_OFFSET_LOCALTIME)
+}
+
+pub fn new_utc(secs: i64, nanos: u32) -> Value {
+    new(secs, nanos, time::RUBY_TIME_OFFSET_UTC)
+}
+
+// `offset` must be within a day, otherwise Ruby raises `ArgumentError`.
+pub fn new_with_offset(secs: i64, nanos: u32, offset: i32) -> Value {
+    new(secs, nanos, offset as c_int)
+}
+
+// Returns seconds since the epoch and the nanoseconds within that second.
+pub fn timespec(time: Value) -> (i64, u32) {
+    let ts = unsafe { time::rb_time_timespec(time) };
+
+    (ts.tv_sec as i64, ts.tv_nsec as u32)
+}
+
+pub fn utc_offset(time: Value) -> i64 {
+    let offset = unsafe { time::rb_time_utc_offset(time) };
+
+    fixnum::num_to_i64(offset)
+}
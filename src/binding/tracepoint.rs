@@ -0,0 +1,72 @@
+use crate::{
+    rubysys::{
+        tracepoint::{self, EventFlag, TraceArgPtr},
+        types::c_void,
+    },
+    types::Value,
+};
+
+pub fn new(
+    target_thread: Value,
+    events: EventFlag,
+    func: extern "C" fn(Value, *mut c_void),
+    data: *mut c_void,
+) -> Value {
+    unsafe { tracepoint::rb_tracepoint_new(target_thread, events, func, data) }
+}
+
+pub fn enable(tracepoint: Value) {
+    unsafe { tracepoint::rb_tracepoint_enable(tracepoint) };
+}
+
+pub fn disable(tracepoint: Value) {
+    unsafe { tracepoint::rb_tracepoint_disable(tracepoint) };
+}
+
+pub fn is_enabled(tracepoint: Value) -> bool {
+    unsafe { tracepoint::rb_tracepoint_enabled_p(tracepoint) }.is_true()
+}
+
+pub fn event_flag(tracepoint: Value) -> EventFlag {
+    unsafe {
+        let trace_arg = tracepoint::rb_tracearg_from_tracepoint(tracepoint);
+
+        tracepoint::rb_tracearg_event_flag(trace_arg)
+    }
+}
+
+pub fn trace_arg(tracepoint: Value) -> TraceArgPtr {
+    unsafe { tracepoint::rb_tracearg_from_tracepoint(tracepoint) }
+}
+
+pub fn trace_arg_event_flag(trace_arg: TraceArgPtr) -> EventFlag {
+    unsafe { tracepoint::rb_tracearg_event_flag(trace_arg) }
+}
+
+pub fn trace_arg_path(trace_arg: TraceArgPtr) -> Value {
+    unsafe { tracepoint::rb_tracearg_path(trace_arg) }
+}
+
+pub fn trace_arg_lineno(trace_arg: TraceArgPtr) -> Value {
+    unsafe { tracepoint::rb_tracearg_lineno(trace_arg) }
+}
+
+pub fn trace_arg_method_id(trace_arg: TraceArgPtr) -> Value {
+    unsafe { tracepoint::rb_tracearg_method_id(trace_arg) }
+}
+
+pub fn trace_arg_defined_class(trace_arg: TraceArgPtr) -> Value {
+    unsafe { tracepoint::rb_tracearg_defined_class(trace_arg) }
+}
+
+pub fn trace_arg_self(trace_arg: TraceArgPtr) -> Value {
+    unsafe { tracepoint::rb_tracearg_self(trace_arg) }
+}
+
+pub fn trace_arg_return_value(trace_arg: TraceArgPtr) -> Value {
+    unsafe { tracepoint::rb_tracearg_return_value(trace_arg) }
+}
+
+pub fn trace_arg_raised_exception(trace_arg: TraceArgPtr) -> Value {
+    unsafe { tracepoint::rb_tracearg_raised_exception(trace_arg) }
+}
@@ -3,7 +3,7 @@ use std::ptr;
 use crate::{
     binding::symbol::internal_id,
     rubysys::{thread, vm},
-    types::{c_int, c_void, CallbackPtr, Value, VmPointer},
+    types::{c_int, c_void, Argc, CallbackPtr, Value, VmPointer},
     util, AnyObject,
 };
 
@@ -61,6 +61,26 @@ pub fn call_method(receiver: Value, method: &str, arguments: &[Value]) -> Value
     unsafe { vm::rb_funcallv(receiver, method_id, argc, argv as *const _) }
 }
 
+pub fn call_method_with_block(
+    receiver: Value,
+    method: &str,
+    arguments: &[Value],
+    block: Value,
+) -> Value {
+    let (argc, argv) = util::process_arguments(arguments);
+    let method_id = internal_id(method);
+
+    unsafe { vm::rb_funcall_with_block(receiver, method_id, argc, argv, block) }
+}
+
+// The last argument is passed as keyword arguments and must be a `Hash`
+pub fn call_method_with_keywords(receiver: Value, method: &str, arguments: &[Value]) -> Value {
+    let (argc, argv) = util::process_arguments(arguments);
+    let method_id = internal_id(method);
+
+    unsafe { vm::rb_funcallv_kw(receiver, method_id, argc, argv as *const _, 1) }
+}
+
 pub fn call_public_method(receiver: Value, method: &str, arguments: &[Value]) -> Value {
     let (argc, argv) = util::process_arguments(arguments);
     let method_id = internal_id(method);
@@ -69,6 +89,19 @@ pub fn call_public_method(receiver: Value, method: &str, arguments: &[Value]) ->
     unsafe { vm::rb_funcallv_public(receiver, method_id, argc, argv as *const _) }
 }
 
+pub fn block_call(
+    receiver: Value,
+    method: &str,
+    arguments: &[Value],
+    block: extern "C" fn(Value, Value, Argc, *const Value) -> Value,
+    data: Value,
+) -> Value {
+    let (argc, argv) = util::process_arguments(arguments);
+    let method_id = internal_id(method);
+
+    unsafe { vm::rb_block_call(receiver, method_id, argc, argv, block, data) }
+}
+
 pub fn call_super(arguments: &[Value]) -> Value {
     let (argc, argv) = util::process_arguments(arguments);
 
@@ -211,6 +244,10 @@ where
     }
 }
 
+pub fn jump_tag(state: c_int) -> ! {
+    unsafe { vm::rb_jump_tag(state) }
+}
+
 pub fn exit(status: i32) {
     unsafe { vm::rb_exit(status as c_int) }
 }
@@ -1,7 +1,9 @@
+use std::slice;
+
 use crate::{
     binding::array,
     types::{Value, ValueType},
-    AnyObject, Enumerator, Object, RString, VerifiedObject,
+    AnyException, AnyObject, ArrayElement, Enumerator, Integer, Object, RString, VerifiedObject,
 };
 
 /// `Array`
@@ -527,6 +529,467 @@ impl Array {
             .try_convert_to::<Enumerator>()
             .unwrap()
     }
+
+    /// Inserts `item` before the element at `index` position.
+    ///
+    /// Negative indices count backwards from the end of the array. Returns `Err` with an
+    /// `IndexError` when `index` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let mut array = Array::new().push(Fixnum::new(1)).push(Fixnum::new(3));
+    ///
+    /// array.insert(1, Fixnum::new(2)).unwrap();
+    ///
+    /// assert_eq!(array.to_vec::<i64>(), Ok(vec![1, 2, 3]));
+    /// assert!(array.insert(-10, Fixnum::new(0)).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 3]
+    /// array.insert(1, 2)
+    ///
+    /// array == [1, 2, 3]
+    /// ```
+    pub fn insert<T: Object>(&mut self, index: i64, item: T) -> Result<(), AnyException> {
+        let arguments = [Integer::new(index).to_any_object(), item.to_any_object()];
+
+        self.protect_send("insert", &arguments).map(|_| ())
+    }
+
+    /// Removes the element at `index` position and returns it, `nil` if `index` is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let mut array = Array::new().push(Fixnum::new(1)).push(Fixnum::new(2));
+    ///
+    /// assert_eq!(array.delete_at(0).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert!(array.delete_at(5).is_nil());
+    /// assert_eq!(array.length(), 1);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 2]
+    ///
+    /// array.delete_at(0) == 1
+    /// array.delete_at(5) == nil
+    /// ```
+    pub fn delete_at(&mut self, index: i64) -> AnyObject {
+        let result = array::delete_at(self.value(), index);
+
+        AnyObject::from(result)
+    }
+
+    /// Returns a new array with `length` elements starting at `start`.
+    ///
+    /// Returns `None` when `start` is out of range, like `Array#slice` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::from_slice(&[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(array.slice(1, 2).unwrap().to_vec::<i64>(), Ok(vec![2, 3]));
+    /// assert_eq!(array.slice(-1, 10).unwrap().to_vec::<i64>(), Ok(vec![4]));
+    /// assert!(array.slice(5, 1).is_none());
+    /// assert!(array.slice(-5, 1).is_none());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 2, 3, 4]
+    ///
+    /// array.slice(1, 2) == [2, 3]
+    /// array.slice(-1, 10) == [4]
+    /// array.slice(5, 1) == nil
+    /// array.slice(-5, 1) == nil
+    /// ```
+    pub fn slice(&self, start: i64, length: usize) -> Option<Array> {
+        // `rb_ary_subseq` does not count negative starts from the end
+        let start = if start < 0 {
+            start + array::len(self.value())
+        } else {
+            start
+        };
+
+        if start < 0 {
+            return None;
+        }
+
+        let length = length.min(i64::MAX as usize) as i64;
+        let result = array::subseq(self.value(), start, length);
+
+        if result.is_nil() {
+            None
+        } else {
+            Some(Array::from(result))
+        }
+    }
+
+    /// Returns `true` if the array contains an element equal to `item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::from_slice(&[1, 2]);
+    ///
+    /// assert!(array.include(&Fixnum::new(2)));
+    /// assert!(!array.include(&Fixnum::new(3)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, 2].include?(2) == true
+    /// [1, 2].include?(3) == false
+    /// ```
+    pub fn include<T: Object>(&self, item: &T) -> bool {
+        array::includes(self.value(), item.value())
+    }
+
+    /// Returns the index of the first element equal to `item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::from_slice(&[1, 2, 2]);
+    ///
+    /// assert_eq!(array.index(&Fixnum::new(2)), Some(1));
+    /// assert_eq!(array.index(&Fixnum::new(3)), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, 2, 2].index(2) == 1
+    /// [1, 2, 2].index(3) == nil
+    /// ```
+    pub fn index<T: Object>(&self, item: &T) -> Option<usize> {
+        let result = unsafe { self.send("index", &[item.to_any_object()]) };
+
+        if result.is_nil() {
+            None
+        } else {
+            Some(Integer::from(result.value()).to_u64() as usize)
+        }
+    }
+
+    /// Returns a new array with all nested arrays recursively flattened into it.
+    ///
+    /// Returns `Err` with an `ArgumentError` when the array contains itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let nested = Array::from_slice(&[2, 3]);
+    /// let array = Array::new().push(Fixnum::new(1)).push(nested);
+    ///
+    /// assert_eq!(array.flatten().unwrap().to_vec::<i64>(), Ok(vec![1, 2, 3]));
+    ///
+    /// let mut recursive = Array::new();
+    /// recursive.push(Array::from(recursive.value()));
+    ///
+    /// assert!(recursive.flatten().is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, [2, 3]].flatten == [1, 2, 3]
+    /// ```
+    pub fn flatten(&self) -> Result<Array, AnyException> {
+        self.protect_send("flatten", &[])
+            .map(|result| Array::from(result.value()))
+    }
+
+    /// Removes all elements from the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, VM};
+    /// # VM::init();
+    ///
+    /// let mut array = Array::from_slice(&[1, 2]);
+    ///
+    /// array.clear();
+    ///
+    /// assert_eq!(array.length(), 0);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 2]
+    /// array.clear
+    ///
+    /// array.length == 0
+    /// ```
+    pub fn clear(&mut self) {
+        array::clear(self.value());
+    }
+
+    /// Returns the first element of the array, `nil` if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::from_slice(&[1, 2]);
+    ///
+    /// assert_eq!(array.first().try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert!(Array::new().first().is_nil());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, 2].first == 1
+    /// [].first == nil
+    /// ```
+    pub fn first(&self) -> AnyObject {
+        self.at(0)
+    }
+
+    /// Returns the last element of the array, `nil` if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::from_slice(&[1, 2]);
+    ///
+    /// assert_eq!(array.last().try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// assert!(Array::new().last().is_nil());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, 2].last == 2
+    /// [].last == nil
+    /// ```
+    pub fn last(&self) -> AnyObject {
+        self.at(-1)
+    }
+
+    /// Replaces the contents of the array with the contents of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, VM};
+    /// # VM::init();
+    ///
+    /// let mut array = Array::from_slice(&[1, 2]);
+    ///
+    /// array.replace(&Array::from_slice(&[3]));
+    ///
+    /// assert_eq!(array.to_vec::<i64>(), Ok(vec![3]));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 2]
+    /// array.replace([3])
+    ///
+    /// array == [3]
+    /// ```
+    pub fn replace(&mut self, other: &Array) -> Array {
+        let result = array::replace(self.value(), other.value());
+
+        Array::from(result)
+    }
+
+    /// Returns a new array without duplicate elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::from_slice(&[1, 2, 1, 3, 2]);
+    ///
+    /// assert_eq!(array.uniq().to_vec::<i64>(), Ok(vec![1, 2, 3]));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, 2, 1, 3, 2].uniq == [1, 2, 3]
+    /// ```
+    pub fn uniq(&self) -> Array {
+        let result = unsafe { self.send("uniq", &[]) };
+
+        Array::from(result.value())
+    }
+
+    /// Returns a new array without `nil` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, NilClass, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new()
+    ///     .push(Fixnum::new(1))
+    ///     .push(NilClass::new())
+    ///     .push(Fixnum::new(2));
+    ///
+    /// assert_eq!(array.compact().to_vec::<i64>(), Ok(vec![1, 2]));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, nil, 2].compact == [1, 2]
+    /// ```
+    pub fn compact(&self) -> Array {
+        let result = unsafe { self.send("compact", &[]) };
+
+        Array::from(result.value())
+    }
+
+    /// Borrows the elements of the array without copying them.
+    ///
+    /// # Safety
+    ///
+    /// The slice points directly into the memory of the array. While it is borrowed
+    /// the array must not be modified, neither from Rust nor from Ruby, and no Ruby
+    /// code that could do so may run. `GC.compact` may move the buffer of an
+    /// embedded array as well, so the slice must not be kept across calls that can
+    /// trigger garbage collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::from_slice(&[1, 2, 3]);
+    ///
+    /// let sum: i64 = unsafe { array.as_slice() }
+    ///     .iter()
+    ///     .map(|item| item.try_convert_to::<Fixnum>().unwrap().to_i64())
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub unsafe fn as_slice(&self) -> &[AnyObject] {
+        let length = self.length();
+
+        if length == 0 {
+            return &[];
+        }
+
+        // `AnyObject` is a `#[repr(C)]` wrapper around `Value`
+        slice::from_raw_parts(array::const_ptr(self.value()) as *const AnyObject, length)
+    }
+
+    /// Converts all elements of the array to Rust values.
+    ///
+    /// Returns `Err` with the error of the first element which cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, RString, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::from_slice(&[1, 2, 3]);
+    ///
+    /// assert_eq!(array.to_vec::<i64>(), Ok(vec![1, 2, 3]));
+    ///
+    /// let array = Array::new().push(Fixnum::new(1)).push(RString::new_utf8("2"));
+    ///
+    /// assert!(array.to_vec::<i64>().is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, 2, 3].map { |item| Integer(item) }
+    /// ```
+    pub fn to_vec<T: ArrayElement>(&self) -> Result<Vec<T>, AnyException> {
+        let mut result = Vec::with_capacity(self.length());
+        let mut index = 0;
+
+        // Conversions may call into Ruby and change the array, so the length and
+        // the buffer are checked again for every element
+        while index < self.length() {
+            let element = AnyObject::from(unsafe { array::entry_unchecked(self.value(), index) });
+
+            result.push(T::from_element(&element)?);
+            index += 1;
+        }
+
+        Ok(result)
+    }
+
+    /// Creates a new `Array` from a slice of Rust values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, RString, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::from_slice(&["a".to_string(), "b".to_string()]);
+    ///
+    /// assert_eq!(array.join(RString::new_utf8(",")).to_str(), "a,b");
+    ///
+    /// let array = Array::from_slice(&[true, false]);
+    ///
+    /// assert_eq!(array.to_vec::<bool>(), Ok(vec![true, false]));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// ["a", "b"]
+    /// ```
+    pub fn from_slice<T: ArrayElement>(items: &[T]) -> Self {
+        let mut array = Array::with_capacity(items.len());
+
+        for item in items {
+            array.push(item.to_element());
+        }
+
+        array
+    }
 }
 
 impl Default for Array {
@@ -595,7 +1058,10 @@ impl Iterator for ArrayIterator {
 
     fn next(&mut self) -> Option<AnyObject> {
         let item = if (self.current_index as usize) < self.len() {
-            Some(self.array.at(self.current_index))
+            let value =
+                unsafe { array::entry_unchecked(self.array.value(), self.current_index as usize) };
+
+            Some(AnyObject::from(value))
         } else {
             None
         };
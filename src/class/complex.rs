@@ -0,0 +1,129 @@
+use std::convert::From;
+
+use crate::{
+    binding::complex,
+    types::{Value, ValueType},
+    AnyObject, Numeric, Object, VerifiedObject,
+};
+
+/// `Complex`
+#[derive(Debug)]
+#[repr(C)]
+pub struct Complex {
+    value: Value,
+}
+
+impl Complex {
+    /// Creates a new `Complex` from its real and imaginary parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Complex, Float, Integer, Object, VM};
+    /// # VM::init();
+    ///
+    /// let complex = Complex::new(Integer::new(1), Float::new(2.5));
+    ///
+    /// assert_eq!(complex.real(), Integer::new(1).to_any_object());
+    /// assert_eq!(complex.imaginary(), Float::new(2.5).to_any_object());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// complex = Complex(1, 2.5)
+    ///
+    /// complex.real == 1
+    /// complex.imaginary == 2.5
+    /// ```
+    pub fn new<R: Numeric, I: Numeric>(real: R, imaginary: I) -> Self {
+        Self::from(complex::new(real.value(), imaginary.value()))
+    }
+
+    /// Returns the real part, which may be any real Ruby numeric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Complex, Integer, Object, VM};
+    /// # VM::init();
+    ///
+    /// let complex = Complex::new(Integer::new(3), Integer::new(4));
+    ///
+    /// assert_eq!(complex.real().try_convert_to::<Integer>(), Ok(Integer::new(3)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Complex(3, 4).real == 3
+    /// ```
+    pub fn real(&self) -> AnyObject {
+        AnyObject::from(complex::real(self.value()))
+    }
+
+    /// Returns the imaginary part, which may be any real Ruby numeric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Complex, Integer, Object, VM};
+    /// # VM::init();
+    ///
+    /// let complex = Complex::new(Integer::new(3), Integer::new(4));
+    ///
+    /// assert_eq!(complex.imaginary().try_convert_to::<Integer>(), Ok(Integer::new(4)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Complex(3, 4).imaginary == 4
+    /// ```
+    pub fn imaginary(&self) -> AnyObject {
+        AnyObject::from(complex::imaginary(self.value()))
+    }
+}
+
+impl From<Value> for Complex {
+    fn from(value: Value) -> Self {
+        Complex { value }
+    }
+}
+
+impl From<Complex> for Value {
+    fn from(val: Complex) -> Self {
+        val.value
+    }
+}
+
+impl From<Complex> for AnyObject {
+    fn from(val: Complex) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for Complex {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl Numeric for Complex {}
+
+impl VerifiedObject for Complex {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Complex
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Complex"
+    }
+}
+
+impl PartialEq for Complex {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
@@ -0,0 +1,133 @@
+use std::{convert::From, time::Duration};
+
+use crate::{types::Value, AnyException, AnyObject, Class, Float, Object, RMutex, VerifiedObject};
+
+/// `ConditionVariable`
+#[derive(Debug)]
+#[repr(C)]
+pub struct ConditionVariable {
+    value: Value,
+}
+
+impl ConditionVariable {
+    /// Creates a new condition variable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rutie::{ConditionVariable, RMutex, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = RMutex::new();
+    /// let condition = ConditionVariable::new();
+    ///
+    /// mutex
+    ///     .synchronize(|| condition.wait(&mutex, Some(Duration::from_millis(1))).unwrap())
+    ///     .unwrap();
+    ///
+    /// condition.signal();
+    /// condition.broadcast();
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// mutex = Mutex.new
+    /// condition = ConditionVariable.new
+    ///
+    /// mutex.synchronize { condition.wait(mutex, 0.001) }
+    ///
+    /// condition.signal
+    /// condition.broadcast
+    /// ```
+    pub fn new() -> Self {
+        let condition = unsafe { Class::from_existing("ConditionVariable").send("new", &[]) };
+
+        Self::from(condition.value())
+    }
+
+    /// Releases `mutex` and waits for a signal or for `timeout` to expire,
+    /// then reacquires `mutex`
+    ///
+    /// The current thread must hold `mutex`. Returns an error if it does not
+    /// or the wait was interrupted.
+    ///
+    /// See `ConditionVariable::new` for an example.
+    pub fn wait(&self, mutex: &RMutex, timeout: Option<Duration>) -> Result<(), AnyException> {
+        let mutex = mutex.to_any_object();
+
+        let result = match timeout {
+            Some(timeout) => {
+                let timeout = Float::new(timeout.as_secs_f64()).to_any_object();
+
+                self.protect_send("wait", &[mutex, timeout])
+            }
+            None => self.protect_send("wait", &[mutex]),
+        };
+
+        result.map(|_| ())
+    }
+
+    /// Wakes up the first thread waiting on the condition variable
+    ///
+    /// See `ConditionVariable::new` for an example.
+    pub fn signal(&self) {
+        unsafe { self.send("signal", &[]) };
+    }
+
+    /// Wakes up all threads waiting on the condition variable
+    ///
+    /// See `ConditionVariable::new` for an example.
+    pub fn broadcast(&self) {
+        unsafe { self.send("broadcast", &[]) };
+    }
+}
+
+impl Default for ConditionVariable {
+    fn default() -> Self {
+        ConditionVariable::new()
+    }
+}
+
+impl From<Value> for ConditionVariable {
+    fn from(value: Value) -> Self {
+        ConditionVariable { value }
+    }
+}
+
+impl From<ConditionVariable> for Value {
+    fn from(val: ConditionVariable) -> Self {
+        val.value
+    }
+}
+
+impl From<ConditionVariable> for AnyObject {
+    fn from(val: ConditionVariable) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for ConditionVariable {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for ConditionVariable {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.class() == Class::from_existing("ConditionVariable")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to ConditionVariable"
+    }
+}
+
+impl PartialEq for ConditionVariable {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
@@ -1,7 +1,10 @@
+use std::collections::HashMap;
+
 use crate::{
     binding::encoding,
     types::{Value, ValueType},
-    AnyException, AnyObject, Class, Exception, NilClass, Object, RString, VerifiedObject,
+    AnyException, AnyObject, Array, Class, Exception, Hash, NilClass, Object, RString,
+    VerifiedObject,
 };
 
 #[derive(Debug)]
@@ -71,6 +74,46 @@ impl Encoding {
         Self::from(encoding::default_external())
     }
 
+    /// Creates an instance of `Encoding` used by Ruby for file paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Encoding, VM};
+    /// # VM::init();
+    ///
+    /// Encoding::filesystem();
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Encoding.find("filesystem")
+    /// ```
+    pub fn filesystem() -> Self {
+        Self::from(encoding::filesystem_encoding())
+    }
+
+    /// Creates an instance of `Encoding` of the current locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Encoding, VM};
+    /// # VM::init();
+    ///
+    /// Encoding::locale();
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Encoding.find("locale")
+    /// ```
+    pub fn locale() -> Self {
+        Self::from(encoding::locale_encoding())
+    }
+
     /// Creates an instance of `Ok(Encoding)` from the default internal encoding
     /// if there is one, otherwise it returns `Err(NilClass)`.
     ///
@@ -174,6 +217,66 @@ impl Encoding {
         }
     }
 
+    /// Returns all encodings loaded into the VM.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Encoding, VM};
+    /// # VM::init();
+    ///
+    /// let names: Vec<String> = Encoding::list().iter().map(|enc| enc.name()).collect();
+    ///
+    /// assert!(names.contains(&"UTF-8".to_string()));
+    /// assert!(names.contains(&"US-ASCII".to_string()));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Encoding.list.map(&:name).include?("UTF-8")
+    /// ```
+    pub fn list() -> Vec<Encoding> {
+        let list = unsafe { Class::from_existing("Encoding").send("list", &[]) };
+
+        Array::from(list.value())
+            .into_iter()
+            .map(|encoding| Encoding::from(encoding.value()))
+            .collect()
+    }
+
+    /// Returns a map from alias names to the names of the encodings they stand for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Encoding, VM};
+    /// # VM::init();
+    ///
+    /// let aliases = Encoding::aliases();
+    ///
+    /// assert_eq!(aliases.get("BINARY").map(String::as_str), Some("ASCII-8BIT"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Encoding.aliases["BINARY"] == "ASCII-8BIT"
+    /// ```
+    pub fn aliases() -> HashMap<String, String> {
+        let aliases = unsafe { Class::from_existing("Encoding").send("aliases", &[]) };
+        let mut result = HashMap::new();
+
+        Hash::from(aliases.value()).each(|alias, name| {
+            let alias = RString::from(alias.value()).to_string();
+            let name = RString::from(name.value()).to_string();
+
+            result.insert(alias, name);
+        });
+
+        result
+    }
+
     /// Returns an instance of `Ok(Encoding)` if the objects are
     /// compatible encodings, otherwise it returns `Err(NilClass)`.
     ///
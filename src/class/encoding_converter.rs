@@ -0,0 +1,382 @@
+use std::{error, fmt};
+
+use crate::{
+    binding::encoding,
+    rubysys::encoding::{
+        EconvPtr, ECONV_CRLF_NEWLINE_DECORATOR, ECONV_CR_NEWLINE_DECORATOR,
+        ECONV_DESTINATION_BUFFER_FULL, ECONV_INCOMPLETE_INPUT, ECONV_INVALID_BYTE_SEQUENCE,
+        ECONV_INVALID_REPLACE, ECONV_PARTIAL_INPUT, ECONV_UNDEFINED_CONVERSION,
+        ECONV_UNDEF_HEX_CHARREF, ECONV_UNDEF_REPLACE, ECONV_UNIVERSAL_NEWLINE_DECORATOR,
+    },
+    AnyException, Encoding, EncodingSupport, Exception, Object, RString,
+};
+
+// Room reserved in the output for each call to `rb_econv_convert`
+const MIN_OUTPUT_CHUNK: usize = 4096;
+
+/// Kind of a `ConversionError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionErrorKind {
+    /// The input contains bytes which are not valid in the source encoding.
+    InvalidByteSequence,
+    /// A character of the input does not exist in the destination encoding.
+    UndefinedConversion,
+    /// The input ends in the middle of a character.
+    IncompleteInput,
+}
+
+/// An error reported by `EncodingConverter`.
+///
+/// The converter skips the offending bytes, so conversion can go on with the input
+/// after `consumed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionError {
+    /// What went wrong.
+    pub kind: ConversionErrorKind,
+    /// Byte offset of `error_bytes` from the beginning of the whole input stream.
+    pub offset: usize,
+    /// Number of bytes of the chunk passed to `convert` which were read.
+    pub consumed: usize,
+    /// The offending bytes.
+    ///
+    /// For a conversion through an intermediate encoding, an undefined character is
+    /// reported in that encoding.
+    pub error_bytes: Vec<u8>,
+    /// Bytes after `error_bytes` which were read, but are converted again.
+    pub readagain_bytes: Vec<u8>,
+    /// The message of the matching Ruby exception.
+    pub message: String,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+impl error::Error for ConversionError {}
+
+impl From<ConversionError> for AnyException {
+    fn from(error: ConversionError) -> Self {
+        let class = match error.kind {
+            ConversionErrorKind::UndefinedConversion => "Encoding::UndefinedConversionError",
+            _ => "Encoding::InvalidByteSequenceError",
+        };
+
+        AnyException::new(class, Some(&error.message))
+    }
+}
+
+/// `Encoding::Converter`
+///
+/// Converts a stream of bytes between encodings chunk by chunk, so the whole input never
+/// has to be held in memory.
+///
+/// # Examples
+///
+/// ```
+/// use rutie::{Encoding, EncodingConverter, VM};
+/// # VM::init();
+/// # VM::init_loadpath(); // Needed for alternate encodings
+/// VM::require("enc/encdb");
+/// VM::require("enc/trans/transdb");
+///
+/// let shift_jis = Encoding::find("Shift_JIS").unwrap();
+/// let mut converter = EncodingConverter::new(&shift_jis, &Encoding::utf8(), 0).unwrap();
+/// let mut output = Vec::new();
+///
+/// // "あ" is split between both chunks
+/// converter.convert(b"a\x82", &mut output).unwrap();
+/// converter.convert(b"\xa0b", &mut output).unwrap();
+/// converter.finish(&mut output).unwrap();
+///
+/// assert_eq!(output, "aあb".as_bytes());
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// converter = Encoding::Converter.new("Shift_JIS", "UTF-8")
+/// output = +""
+///
+/// converter.primitive_convert("a\x82", output, nil, nil, partial_input: true)
+/// converter.primitive_convert("\xa0b", output, nil, nil, partial_input: true)
+/// converter.finish
+///
+/// output == "aあb"
+/// ```
+#[derive(Debug)]
+pub struct EncodingConverter {
+    converter: EconvPtr,
+    position: usize,
+}
+
+impl EncodingConverter {
+    /// Replaces invalid byte sequences instead of reporting them.
+    pub const INVALID_REPLACE: i32 = ECONV_INVALID_REPLACE;
+    /// Replaces characters undefined in the destination encoding instead of reporting them.
+    pub const UNDEF_REPLACE: i32 = ECONV_UNDEF_REPLACE;
+    /// Replaces characters undefined in the destination encoding with XML character
+    /// references like `&#x2603;`.
+    pub const UNDEF_HEX_CHARREF: i32 = ECONV_UNDEF_HEX_CHARREF;
+    /// Converts CRLF and CR newlines to LF.
+    pub const UNIVERSAL_NEWLINE_DECORATOR: i32 = ECONV_UNIVERSAL_NEWLINE_DECORATOR;
+    /// Converts LF newlines to CRLF.
+    pub const CRLF_NEWLINE_DECORATOR: i32 = ECONV_CRLF_NEWLINE_DECORATOR;
+    /// Converts LF newlines to CR.
+    pub const CR_NEWLINE_DECORATOR: i32 = ECONV_CR_NEWLINE_DECORATOR;
+
+    /// Creates a new converter from `source` to `destination`.
+    ///
+    /// `flags` is a combination of the constants of `EncodingConverter`. Returns `Err`
+    /// with an `Encoding::ConverterNotFoundError` when Ruby cannot convert between the
+    /// encodings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Encoding, EncodingConverter, VM};
+    /// # VM::init();
+    /// # VM::init_loadpath(); // Needed for alternate encodings
+    /// VM::require("enc/encdb");
+    /// VM::require("enc/trans/transdb");
+    ///
+    /// let latin1 = Encoding::find("ISO-8859-1").unwrap();
+    /// let flags = EncodingConverter::UNIVERSAL_NEWLINE_DECORATOR;
+    /// let mut converter = EncodingConverter::new(&Encoding::utf8(), &latin1, flags).unwrap();
+    /// let mut output = Vec::new();
+    ///
+    /// converter.convert(b"a\r\nb\r", &mut output).unwrap();
+    /// converter.finish(&mut output).unwrap();
+    ///
+    /// assert_eq!(output, b"a\nb\n");
+    ///
+    /// let binary = Encoding::find("ASCII-8BIT").unwrap();
+    ///
+    /// assert!(EncodingConverter::new(&binary, &Encoding::utf8(), 0).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Encoding::Converter.new("UTF-8", "ISO-8859-1", universal_newline: true)
+    /// ```
+    pub fn new(
+        source: &Encoding,
+        destination: &Encoding,
+        flags: i32,
+    ) -> Result<Self, AnyException> {
+        let source = source.name();
+        let destination = destination.name();
+
+        let converter = encoding::econv_open(&source, &destination, flags);
+
+        if converter.is_null() {
+            let message = format!("code converter not found ({} to {})", source, destination);
+
+            return Err(AnyException::new(
+                "Encoding::ConverterNotFoundError",
+                Some(&message),
+            ));
+        }
+
+        Ok(EncodingConverter {
+            converter,
+            position: 0,
+        })
+    }
+
+    /// Sets the string used by `INVALID_REPLACE` and `UNDEF_REPLACE`.
+    ///
+    /// Defaults to `U+FFFD` for a Unicode destination and `?` otherwise. Returns `Err`
+    /// with an `Encoding::UndefinedConversionError` when `replacement` cannot be
+    /// converted to the destination encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Encoding, EncodingConverter, RString, VM};
+    /// # VM::init();
+    /// # VM::init_loadpath(); // Needed for alternate encodings
+    /// VM::require("enc/encdb");
+    /// VM::require("enc/trans/transdb");
+    ///
+    /// let latin1 = Encoding::find("ISO-8859-1").unwrap();
+    /// let flags = EncodingConverter::INVALID_REPLACE;
+    /// let mut converter = EncodingConverter::new(&Encoding::utf8(), &latin1, flags).unwrap();
+    /// let mut output = Vec::new();
+    ///
+    /// converter.set_replacement(&RString::new_utf8("*")).unwrap();
+    /// converter.convert(b"a\xffb", &mut output).unwrap();
+    /// converter.finish(&mut output).unwrap();
+    ///
+    /// assert_eq!(output, b"a*b");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// converter = Encoding::Converter.new("UTF-8", "ISO-8859-1", invalid: :replace)
+    /// converter.replacement = "*"
+    /// ```
+    pub fn set_replacement(&mut self, replacement: &RString) -> Result<(), AnyException> {
+        let bytes = replacement.to_bytes_unchecked();
+        let encoding_name = replacement.encoding().name();
+
+        if encoding::econv_set_replacement(self.converter, bytes, &encoding_name) {
+            Ok(())
+        } else {
+            Err(AnyException::new(
+                "Encoding::UndefinedConversionError",
+                Some("replacement cannot be converted to the destination encoding"),
+            ))
+        }
+    }
+
+    /// Converts a chunk of input and appends the result to `output`.
+    ///
+    /// A character split between chunks is kept until the next call. On error the
+    /// offending bytes are skipped and `ConversionError::consumed` tells how much of
+    /// `input` was read, so conversion can go on with the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{ConversionErrorKind, Encoding, EncodingConverter, VM};
+    /// # VM::init();
+    /// # VM::init_loadpath(); // Needed for alternate encodings
+    /// VM::require("enc/encdb");
+    /// VM::require("enc/trans/transdb");
+    ///
+    /// let latin1 = Encoding::find("ISO-8859-1").unwrap();
+    /// let mut converter = EncodingConverter::new(&Encoding::utf8(), &latin1, 0).unwrap();
+    /// let mut output = Vec::new();
+    ///
+    /// let input = b"ab\xffcd";
+    /// let error = converter.convert(input, &mut output).unwrap_err();
+    ///
+    /// assert_eq!(error.kind, ConversionErrorKind::InvalidByteSequence);
+    /// assert_eq!(error.offset, 2);
+    /// assert_eq!(error.error_bytes, b"\xff");
+    ///
+    /// converter.convert(&input[error.consumed..], &mut output).unwrap();
+    /// converter.finish(&mut output).unwrap();
+    ///
+    /// assert_eq!(output, b"abcd");
+    /// ```
+    pub fn convert(&mut self, input: &[u8], output: &mut Vec<u8>) -> Result<(), ConversionError> {
+        self.run(input, output, ECONV_PARTIAL_INPUT)
+    }
+
+    /// Flushes the converter after the last chunk of input.
+    ///
+    /// Returns `Err` with `ConversionErrorKind::IncompleteInput` when the input ended in
+    /// the middle of a character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{ConversionErrorKind, Encoding, EncodingConverter, VM};
+    /// # VM::init();
+    /// # VM::init_loadpath(); // Needed for alternate encodings
+    /// VM::require("enc/encdb");
+    /// VM::require("enc/trans/transdb");
+    ///
+    /// let latin1 = Encoding::find("ISO-8859-1").unwrap();
+    /// let mut converter = EncodingConverter::new(&Encoding::utf8(), &latin1, 0).unwrap();
+    /// let mut output = Vec::new();
+    ///
+    /// converter.convert(b"a\xe3\x81", &mut output).unwrap();
+    ///
+    /// let error = converter.finish(&mut output).unwrap_err();
+    ///
+    /// assert_eq!(error.kind, ConversionErrorKind::IncompleteInput);
+    /// assert_eq!(error.offset, 1);
+    /// assert_eq!(output, b"a");
+    /// ```
+    pub fn finish(&mut self, output: &mut Vec<u8>) -> Result<(), ConversionError> {
+        self.run(&[], output, 0)
+    }
+
+    fn run(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+        flags: i32,
+    ) -> Result<(), ConversionError> {
+        let mut consumed = 0;
+
+        loop {
+            let start = output.len();
+            let room = (input.len() - consumed)
+                .saturating_mul(2)
+                .max(MIN_OUTPUT_CHUNK);
+
+            output.resize(start + room, 0);
+
+            let (result, read, written) = encoding::econv_convert(
+                self.converter,
+                &input[consumed..],
+                &mut output[start..],
+                flags,
+            );
+
+            output.truncate(start + written);
+            consumed += read;
+            self.position += read;
+
+            match result {
+                ECONV_DESTINATION_BUFFER_FULL => continue,
+                ECONV_INVALID_BYTE_SEQUENCE => {
+                    return Err(self.last_error(ConversionErrorKind::InvalidByteSequence, consumed))
+                }
+                ECONV_UNDEFINED_CONVERSION => {
+                    return Err(self.last_error(ConversionErrorKind::UndefinedConversion, consumed))
+                }
+                ECONV_INCOMPLETE_INPUT => {
+                    return Err(self.last_error(ConversionErrorKind::IncompleteInput, consumed))
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn last_error(&self, kind: ConversionErrorKind, consumed: usize) -> ConversionError {
+        let exception = AnyException::from(encoding::econv_make_exception(self.converter));
+
+        let bytes = |method: &str| -> Vec<u8> {
+            if exception.respond_to(method) {
+                let result = unsafe { exception.send(method, &[]) };
+
+                RString::from(result.value()).to_vec_u8_unchecked()
+            } else {
+                Vec::new()
+            }
+        };
+
+        let error_bytes = match kind {
+            ConversionErrorKind::UndefinedConversion => bytes("error_char"),
+            _ => bytes("error_bytes"),
+        };
+        let readagain_bytes = bytes("readagain_bytes");
+
+        let offset = self
+            .position
+            .saturating_sub(error_bytes.len() + readagain_bytes.len());
+
+        ConversionError {
+            kind,
+            offset,
+            consumed,
+            error_bytes,
+            readagain_bytes,
+            message: exception.message(),
+        }
+    }
+}
+
+impl Drop for EncodingConverter {
+    fn drop(&mut self) {
+        encoding::econv_close(self.converter);
+    }
+}
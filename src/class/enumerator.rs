@@ -1,6 +1,57 @@
-use std::convert::From;
+use std::{convert::From, sync::Once};
 
-use crate::{types::Value, AnyException, AnyObject, Array, Class, Object, VerifiedObject};
+use crate::{
+    binding::enumerator,
+    types::{Argc, Value},
+    wrappable_struct, AnyException, AnyObject, Array, Class, Integer, Module, NilClass, Object,
+    VerifiedObject, VM,
+};
+
+pub struct IteratorSource {
+    iter: Box<dyn Iterator<Item = AnyObject>>,
+}
+
+wrappable_struct!(
+    IteratorSource,
+    IteratorSourceWrapper,
+    ITERATOR_SOURCE_WRAPPER
+);
+
+// `Rutie::IteratorSource` holds a Rust iterator and yields its items from `each`.
+fn iterator_source_class() -> Class {
+    static DEFINE: Once = Once::new();
+
+    DEFINE.call_once(|| {
+        Module::new("Rutie")
+            .define_nested_class("IteratorSource", None)
+            .define(|klass| {
+                klass.def("each", iterator_source_each);
+            });
+    });
+
+    Module::from_existing("Rutie").get_nested_class("IteratorSource")
+}
+
+extern "C" fn iterator_source_each(
+    _argc: Argc,
+    _argv: *const AnyObject,
+    mut rtself: AnyObject,
+) -> AnyObject {
+    while let Some(item) = rtself.get_data_mut(&*ITERATOR_SOURCE_WRAPPER).iter.next() {
+        VM::yield_object(item);
+    }
+
+    rtself
+}
+
+extern "C" fn iterator_source_size(object: Value, _arguments: Value, _enumerator: Value) -> Value {
+    let source = AnyObject::from(object);
+
+    match source.get_data(&*ITERATOR_SOURCE_WRAPPER).iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => Integer::from(lower as u64).value(),
+        _ => NilClass::new().value(),
+    }
+}
 
 /// `Enumerator`
 #[derive(Debug)]
@@ -10,6 +61,100 @@ pub struct Enumerator {
 }
 
 impl Enumerator {
+    /// Creates an `Enumerator` that yields the items of a Rust iterator on demand.
+    ///
+    /// The iterator is consumed as Ruby iterates, both internally with `each` and
+    /// externally with `next`, so nothing is collected up front. Like a stream it
+    /// can only be iterated once: `rewind` and a second `each` continue where the
+    /// previous iteration stopped. `size` returns the number of remaining items
+    /// when the iterator's `size_hint` is exact, and `nil` otherwise.
+    ///
+    /// Ruby objects captured by the iterator are not marked by the GC. Keep them
+    /// reachable from Ruby, or create the yielded objects from Rust data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Enumerator, Integer, Object, VM};
+    /// # VM::init();
+    ///
+    /// let mut enumerator = Enumerator::from_iter((1..=3).map(Integer::new));
+    ///
+    /// let size = unsafe { enumerator.send("size", &[]) };
+    ///
+    /// assert_eq!(size, Integer::new(3).to_any_object());
+    /// assert_eq!(enumerator.next(), Ok(Integer::new(1).to_any_object()));
+    /// assert_eq!(enumerator.next(), Ok(Integer::new(2).to_any_object()));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// enumerator = [1, 2, 3].each
+    ///
+    /// enumerator.size == 3
+    /// enumerator.next == 1
+    /// enumerator.next == 2
+    /// ```
+    #[allow(clippy::should_implement_trait)] // The iterator has to be `'static`.
+    pub fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Object,
+        I::IntoIter: 'static,
+    {
+        let source = IteratorSource {
+            iter: Box::new(iter.into_iter().map(|item| item.to_any_object())),
+        };
+
+        let source: AnyObject =
+            iterator_source_class().wrap_data(source, &*ITERATOR_SOURCE_WRAPPER);
+
+        Self::from(enumerator::enumeratorize_with_size(
+            source.value(),
+            "each",
+            &[],
+            Some(iterator_source_size),
+        ))
+    }
+
+    /// Creates an `Enumerator::Lazy` that yields the items of a Rust iterator on demand.
+    ///
+    /// Chained calls like `map` and `select` are evaluated item by item, so only the
+    /// items Ruby asks for are pulled from the iterator. See `from_iter` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Enumerator, Integer, Object, VM};
+    /// # VM::init();
+    ///
+    /// // An infinite iterator
+    /// let lazy = Enumerator::lazy_from_iter((1..).map(Integer::new));
+    ///
+    /// let first_even_squares = VM::eval("->(lazy) { lazy.select(&:even?).map { _1 ** 2 }.first(3) }")
+    ///     .unwrap();
+    /// let result = unsafe { first_even_squares.send("call", &[lazy.to_any_object()]) };
+    ///
+    /// assert_eq!(result, VM::eval("[4, 16, 36]").unwrap());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// (1..).lazy.select(&:even?).map { _1 ** 2 }.first(3) == [4, 16, 36]
+    /// ```
+    pub fn lazy_from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Object,
+        I::IntoIter: 'static,
+    {
+        let lazy = unsafe { Self::from_iter(iter).send("lazy", &[]) };
+
+        Self::from(lazy.value())
+    }
+
     /// Advances the iterator and returns the next value.
     ///
     /// Returns [`Err`] when iteration is finished.
@@ -152,6 +297,39 @@ impl Enumerator {
         self
     }
 
+    /// Returns an iterator over the remaining values of the enumerator.
+    ///
+    /// The iterator advances the enumerator itself with `next`, ends cleanly on
+    /// `StopIteration` and returns any other exception as an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, Object, VM, VerifiedObject, Enumerator};
+    /// # VM::init();
+    ///
+    /// let enumerator = VM::eval("[1, 2, 3].each").unwrap()
+    ///     .try_convert_to::<Enumerator>().unwrap();
+    ///
+    /// let sum: i64 = enumerator.iter()
+    ///     .map(|value| value.unwrap().try_convert_to::<Integer>().unwrap().to_i64())
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 6);
+    ///
+    /// let failing = VM::eval("Enumerator.new { |y| y << 1; raise 'broken' }").unwrap()
+    ///     .try_convert_to::<Enumerator>().unwrap();
+    ///
+    /// let mut iter = failing.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(Integer::new(1).to_any_object())));
+    /// assert!(iter.next().unwrap().is_err());
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn iter(&self) -> EnumeratorIter {
+        Enumerator::from(self.value()).into_iter()
+    }
+
     /// Feed a return value back in to internal yield inside enumerator.
     ///
     /// # Examples
@@ -205,6 +383,52 @@ impl Enumerator {
     }
 }
 
+/// An iterator over the values of an `Enumerator`.
+///
+/// Created by `Enumerator::iter` or by iterating an `Enumerator` directly. It ends when the
+/// enumerator raises `StopIteration`; any other exception is returned once as an `Err`,
+/// after which the iterator is finished.
+#[derive(Debug)]
+pub struct EnumeratorIter {
+    enumerator: Enumerator,
+    finished: bool,
+}
+
+impl Iterator for EnumeratorIter {
+    type Item = Result<AnyObject, AnyException>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.enumerator.next() {
+            Ok(value) => Some(Ok(value)),
+            Err(exception) => {
+                self.finished = true;
+
+                if Class::from_existing("StopIteration").case_equals(&exception) {
+                    None
+                } else {
+                    Some(Err(exception))
+                }
+            }
+        }
+    }
+}
+
+impl IntoIterator for Enumerator {
+    type Item = Result<AnyObject, AnyException>;
+    type IntoIter = EnumeratorIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        EnumeratorIter {
+            enumerator: self,
+            finished: false,
+        }
+    }
+}
+
 impl From<Value> for Enumerator {
     fn from(value: Value) -> Self {
         Enumerator { value }
@@ -0,0 +1,236 @@
+use std::convert::From;
+
+use crate::{
+    binding::fiber,
+    types::{Argc, Value},
+    util, wrappable_struct, AnyException, AnyObject, Class, Object, VerifiedObject,
+};
+
+#[cfg(ruby_gte_3_2)]
+use crate::Symbol;
+
+pub struct FiberBody {
+    func: Option<Box<dyn FnOnce(AnyObject) -> AnyObject>>,
+}
+
+wrappable_struct!(FiberBody, FiberBodyWrapper, FIBER_BODY_WRAPPER);
+
+extern "C" fn fiber_callback(
+    yielded_arg: Value,
+    callback_arg: Value,
+    _argc: Argc,
+    _argv: *const Value,
+    _blockarg: Value,
+) -> Value {
+    let mut holder = AnyObject::from(callback_arg);
+    let func = holder.get_data_mut(&*FIBER_BODY_WRAPPER).func.take();
+
+    match func {
+        Some(func) => func(AnyObject::from(yielded_arg)).value(),
+        None => yielded_arg,
+    }
+}
+
+/// `Fiber`
+#[derive(Debug)]
+#[repr(C)]
+pub struct Fiber {
+    value: Value,
+}
+
+impl Fiber {
+    /// Creates a new fiber which runs `func` when it is first resumed
+    ///
+    /// The closure receives the first argument passed to `resume` and its
+    /// return value becomes the result of the last `resume`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fiber, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let fiber = Fiber::new(|start| {
+    ///     let start = start.try_convert_to::<Fixnum>().unwrap().to_i64();
+    ///
+    ///     let next = Fiber::yield_value(&[Fixnum::new(start + 1).to_any_object()]);
+    ///     let next = next.try_convert_to::<Fixnum>().unwrap().to_i64();
+    ///
+    ///     Fixnum::new(next * 10)
+    /// });
+    ///
+    /// let first = fiber.resume(&[Fixnum::new(1).to_any_object()]).unwrap();
+    /// assert_eq!(first.try_convert_to::<Fixnum>().unwrap().to_i64(), 2);
+    ///
+    /// let last = fiber.resume(&[Fixnum::new(5).to_any_object()]).unwrap();
+    /// assert_eq!(last.try_convert_to::<Fixnum>().unwrap().to_i64(), 50);
+    ///
+    /// assert!(!fiber.is_alive());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// fiber = Fiber.new do |start|
+    ///   next_value = Fiber.yield(start + 1)
+    ///
+    ///   next_value * 10
+    /// end
+    ///
+    /// fiber.resume(1) == 2
+    /// fiber.resume(5) == 50
+    /// ```
+    pub fn new<F, R>(func: F) -> Self
+    where
+        F: FnOnce(AnyObject) -> R + 'static,
+        R: Object,
+    {
+        let body = FiberBody {
+            func: Some(Box::new(move |argument| func(argument).to_any_object())),
+        };
+
+        // The fiber keeps `holder` alive, so the closure is freed with the fiber.
+        let holder: AnyObject =
+            Class::from_existing("Object").wrap_data(body, &*FIBER_BODY_WRAPPER);
+
+        Self::from(fiber::new(fiber_callback, holder.value()))
+    }
+
+    /// Returns the fiber which is currently running
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fiber, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Fiber::current(), Fiber::current());
+    /// assert!(Fiber::current().is_alive());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Fiber.current
+    /// ```
+    pub fn current() -> Self {
+        Self::from(fiber::current())
+    }
+
+    /// Resumes the fiber, passing `arguments` to it
+    ///
+    /// Returns the values given to `Fiber::yield_value`, or the result of the
+    /// fiber once it finished. Returns an error if the fiber is dead or raised
+    /// an exception.
+    ///
+    /// See `Fiber::new` for an example.
+    pub fn resume(&self, arguments: &[AnyObject]) -> Result<AnyObject, AnyException> {
+        let value = self.value();
+        let arguments = util::arguments_to_values(arguments);
+
+        util::protect(|| AnyObject::from(fiber::resume(value, &arguments)))
+    }
+
+    /// Suspends the current fiber, returning `arguments` from `resume`
+    ///
+    /// Returns the arguments of the next `resume`. Raises `FiberError` when
+    /// called from the root fiber.
+    ///
+    /// See `Fiber::new` for an example.
+    pub fn yield_value(arguments: &[AnyObject]) -> AnyObject {
+        let arguments = util::arguments_to_values(arguments);
+
+        AnyObject::from(fiber::yield_values(&arguments))
+    }
+
+    /// Returns `true` if the fiber can still be resumed
+    ///
+    /// See `Fiber::new` for an example.
+    pub fn is_alive(&self) -> bool {
+        fiber::is_alive(self.value())
+    }
+
+    /// Returns the value of `key` in the current fiber storage
+    ///
+    /// Fiber storage is inherited by fibers and threads created from the
+    /// current fiber. Available on Ruby 3.2 and later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fiber, Fixnum, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let key = Symbol::new("request_id");
+    ///
+    /// assert!(Fiber::get_storage(&key).is_nil());
+    ///
+    /// Fiber::set_storage(&key, Fixnum::new(42));
+    ///
+    /// assert_eq!(Fiber::get_storage(&key).try_convert_to::<Fixnum>(), Ok(Fixnum::new(42)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Fiber[:request_id] = 42
+    /// Fiber[:request_id] == 42
+    /// ```
+    #[cfg(ruby_gte_3_2)]
+    pub fn get_storage(key: &Symbol) -> AnyObject {
+        let class = Class::from_existing("Fiber");
+
+        unsafe { class.send("[]", &[key.to_any_object()]) }
+    }
+
+    /// Sets `key` in the current fiber storage
+    ///
+    /// See `Fiber::get_storage` for an example.
+    #[cfg(ruby_gte_3_2)]
+    pub fn set_storage<T: Object>(key: &Symbol, value: T) {
+        let class = Class::from_existing("Fiber");
+
+        unsafe { class.send("[]=", &[key.to_any_object(), value.to_any_object()]) };
+    }
+}
+
+impl From<Value> for Fiber {
+    fn from(value: Value) -> Self {
+        Fiber { value }
+    }
+}
+
+impl From<Fiber> for Value {
+    fn from(val: Fiber) -> Self {
+        val.value
+    }
+}
+
+impl From<Fiber> for AnyObject {
+    fn from(val: Fiber) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for Fiber {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for Fiber {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.class() == Class::from_existing("Fiber")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Fiber"
+    }
+}
+
+impl PartialEq for Fiber {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
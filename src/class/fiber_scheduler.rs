@@ -0,0 +1,148 @@
+use std::{convert::From, time::Duration};
+
+use crate::{
+    binding::fiber, types::Value, util, AnyException, AnyObject, Fiber, Float, Integer, NilClass,
+    Object,
+};
+
+/// The fiber scheduler of the current thread
+///
+/// Blocking operations in extensions should go through the scheduler when one
+/// is set, so they suspend only the current fiber instead of stalling the
+/// scheduler's event loop.
+#[derive(Debug)]
+#[repr(C)]
+pub struct FiberScheduler {
+    value: Value,
+}
+
+impl FiberScheduler {
+    /// Wait until the IO is readable
+    pub const READABLE: i64 = 1;
+    /// Wait until the IO has priority data
+    pub const PRIORITY: i64 = 2;
+    /// Wait until the IO is writable
+    pub const WRITABLE: i64 = 4;
+
+    /// Returns the scheduler of the current thread, if it is set and not blocking
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{FiberScheduler, VM};
+    /// # VM::init();
+    ///
+    /// assert!(FiberScheduler::current().is_none());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Fiber.current_scheduler
+    /// ```
+    pub fn current() -> Option<Self> {
+        let scheduler = fiber::scheduler_current();
+
+        if scheduler.is_nil() {
+            None
+        } else {
+            Some(Self::from(scheduler))
+        }
+    }
+
+    /// Waits until `io` is ready for `events`, a combination of `READABLE`,
+    /// `PRIORITY` and `WRITABLE`
+    ///
+    /// Returns the events which are ready, or `false` on timeout.
+    pub fn io_wait(
+        &self,
+        io: &impl Object,
+        events: i64,
+        timeout: Option<Duration>,
+    ) -> Result<AnyObject, AnyException> {
+        let (scheduler, io) = (self.value(), io.value());
+        let events = Integer::new(events).value();
+        let timeout = timeout_to_value(timeout);
+
+        util::protect(|| AnyObject::from(fiber::scheduler_io_wait(scheduler, io, events, timeout)))
+    }
+
+    /// Waits until `io` is readable
+    pub fn io_wait_readable(&self, io: &impl Object) -> Result<AnyObject, AnyException> {
+        let (scheduler, io) = (self.value(), io.value());
+
+        util::protect(|| AnyObject::from(fiber::scheduler_io_wait_readable(scheduler, io)))
+    }
+
+    /// Waits until `io` is writable
+    pub fn io_wait_writable(&self, io: &impl Object) -> Result<AnyObject, AnyException> {
+        let (scheduler, io) = (self.value(), io.value());
+
+        util::protect(|| AnyObject::from(fiber::scheduler_io_wait_writable(scheduler, io)))
+    }
+
+    /// Suspends the current fiber for `duration`, or until it is woken up when
+    /// `duration` is `None`
+    pub fn kernel_sleep(&self, duration: Option<Duration>) -> Result<(), AnyException> {
+        let scheduler = self.value();
+        let duration = timeout_to_value(duration);
+
+        util::protect(|| AnyObject::from(fiber::scheduler_kernel_sleep(scheduler, duration)))
+            .map(|_| ())
+    }
+
+    /// Suspends the current fiber until `unblock` is called with the same
+    /// `blocker` or `timeout` expires
+    pub fn block(
+        &self,
+        blocker: &impl Object,
+        timeout: Option<Duration>,
+    ) -> Result<AnyObject, AnyException> {
+        let (scheduler, blocker) = (self.value(), blocker.value());
+        let timeout = timeout_to_value(timeout);
+
+        util::protect(|| AnyObject::from(fiber::scheduler_block(scheduler, blocker, timeout)))
+    }
+
+    /// Wakes up `fiber`, which was suspended by `block` with the same `blocker`
+    ///
+    /// Unlike the other methods this may be called from another thread.
+    pub fn unblock(&self, blocker: &impl Object, fiber: &Fiber) -> Result<(), AnyException> {
+        let (scheduler, blocker, target) = (self.value(), blocker.value(), fiber.value());
+
+        util::protect(|| AnyObject::from(fiber::scheduler_unblock(scheduler, blocker, target)))
+            .map(|_| ())
+    }
+}
+
+fn timeout_to_value(timeout: Option<Duration>) -> Value {
+    match timeout {
+        Some(timeout) => Float::new(timeout.as_secs_f64()).value(),
+        None => NilClass::new().value(),
+    }
+}
+
+impl From<Value> for FiberScheduler {
+    fn from(value: Value) -> Self {
+        FiberScheduler { value }
+    }
+}
+
+impl From<FiberScheduler> for Value {
+    fn from(val: FiberScheduler) -> Self {
+        val.value
+    }
+}
+
+impl From<FiberScheduler> for AnyObject {
+    fn from(val: FiberScheduler) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for FiberScheduler {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
@@ -3,7 +3,7 @@ use std::convert::From;
 use crate::{
     binding::fixnum,
     types::{Value, ValueType},
-    AnyObject, Object, VerifiedObject,
+    AnyObject, Numeric, Object, VerifiedObject,
 };
 
 /// `Fixnum`
@@ -150,6 +150,8 @@ impl Object for Fixnum {
     }
 }
 
+impl Numeric for Fixnum {}
+
 impl VerifiedObject for Fixnum {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Fixnum
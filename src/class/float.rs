@@ -3,7 +3,7 @@ use std::convert::From;
 use crate::{
     binding::float,
     types::{Value, ValueType},
-    AnyException, AnyObject, Object, VerifiedObject,
+    AnyException, AnyObject, Numeric, Object, VerifiedObject,
 };
 
 /// `Float`
@@ -107,6 +107,8 @@ impl Object for Float {
     }
 }
 
+impl Numeric for Float {}
+
 impl VerifiedObject for Float {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Float
@@ -1,4 +1,14 @@
-use crate::{binding::gc, Object, Symbol};
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+    binding::{gc, tracepoint},
+    rubysys::tracepoint::{
+        EventFlag, RUBY_INTERNAL_EVENT_GC_END_MARK, RUBY_INTERNAL_EVENT_GC_END_SWEEP,
+        RUBY_INTERNAL_EVENT_GC_ENTER, RUBY_INTERNAL_EVENT_GC_EXIT, RUBY_INTERNAL_EVENT_GC_START,
+    },
+    types::{c_void, Value},
+    AnyException, Boolean, Hash, Integer, Module, NilClass, Object, Symbol,
+};
 
 /// Garbage collection
 pub struct GC;
@@ -238,6 +248,296 @@ impl GC {
         gc::stat(key.value())
     }
 
+    /// Returns a typed snapshot of `GC.stat`
+    ///
+    /// Keys which only exist on some Ruby versions are exposed as `Option`s,
+    /// every key reported by the running Ruby is also available through
+    /// `GcStats::get`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{GC, VM};
+    /// # VM::init();
+    ///
+    /// GC::start();
+    ///
+    /// let stats = GC::stats();
+    ///
+    /// assert!(stats.count >= 1);
+    /// assert!(stats.heap_live_slots <= stats.heap_available_slots);
+    /// assert_eq!(stats.get("count"), Some(stats.count));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.start
+    ///
+    /// stats = GC.stat
+    ///
+    /// stats[:count] >= 1
+    /// ```
+    pub fn stats() -> GcStats {
+        let hash = unsafe { gc_module().send("stat", &[]) };
+        let hash = Hash::from(hash.value());
+
+        let mut values = HashMap::new();
+
+        hash.each(|key, value| {
+            if let (Ok(key), Ok(value)) = (
+                key.try_convert_to::<Symbol>(),
+                value.try_convert_to::<Integer>(),
+            ) {
+                values.insert(key.to_string(), value.to_u64() as usize);
+            }
+        });
+
+        GcStats::from_values(values)
+    }
+
+    /// Compacts the heap, moving objects to reduce fragmentation
+    ///
+    /// Returns an error (`NotImplementedError`) on platforms where compaction
+    /// is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{GC, VM};
+    /// # VM::init();
+    ///
+    /// let _ = GC::compact();
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.compact
+    /// ```
+    pub fn compact() -> Result<(), AnyException> {
+        gc_module().protect_send("compact", &[]).map(|_| ())
+    }
+
+    /// Returns information about the most recent garbage collection
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{GC, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// GC::start();
+    ///
+    /// let info = GC::latest_info();
+    ///
+    /// assert!(!info.at(&Symbol::new("major_by")).is_nil());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.start
+    ///
+    /// GC.latest_gc_info[:major_by]
+    /// ```
+    pub fn latest_info() -> Hash {
+        let hash = unsafe { gc_module().send("latest_gc_info", &[]) };
+
+        Hash::from(hash.value())
+    }
+
+    /// Returns `true` if GC stress mode is enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{GC, VM};
+    /// # VM::init();
+    ///
+    /// assert!(!GC::is_stress());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.stress == false
+    /// ```
+    pub fn is_stress() -> bool {
+        unsafe { gc_module().send("stress", &[]) }.value().is_true()
+    }
+
+    /// Enables or disables GC stress mode, which runs GC at every opportunity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{GC, VM};
+    /// # VM::init();
+    ///
+    /// GC::set_stress(true);
+    /// assert!(GC::is_stress());
+    ///
+    /// GC::set_stress(false);
+    /// assert!(!GC::is_stress());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.stress = true
+    /// GC.stress = false
+    /// ```
+    pub fn set_stress(enabled: bool) {
+        let enabled = Boolean::new(enabled).to_any_object();
+
+        unsafe { gc_module().send("stress=", &[enabled]) };
+    }
+
+    /// Returns `true` if Ruby measures the time spent in GC
+    ///
+    /// Returns an error on Rubies older than 3.1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{GC, VM};
+    /// # VM::init();
+    ///
+    /// let _ = GC::measure_total_time();
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.measure_total_time
+    /// ```
+    pub fn measure_total_time() -> Result<bool, AnyException> {
+        gc_module()
+            .protect_send("measure_total_time", &[])
+            .map(|result| result.value().is_true())
+    }
+
+    /// Enables or disables measuring the time spent in GC
+    ///
+    /// Returns an error on Rubies older than 3.1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{GC, VM};
+    /// # VM::init();
+    ///
+    /// if GC::set_measure_total_time(true).is_ok() {
+    ///     assert_eq!(GC::measure_total_time(), Ok(true));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.measure_total_time = true
+    /// ```
+    pub fn set_measure_total_time(enabled: bool) -> Result<(), AnyException> {
+        let enabled = Boolean::new(enabled).to_any_object();
+
+        gc_module()
+            .protect_send("measure_total_time=", &[enabled])
+            .map(|_| ())
+    }
+
+    /// Returns the total time spent in GC
+    ///
+    /// Returns an error on Rubies older than 3.1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{GC, VM};
+    /// # VM::init();
+    ///
+    /// if let Ok(total_time) = GC::total_time() {
+    ///     GC::start();
+    ///
+    ///     assert!(GC::total_time().unwrap() >= total_time);
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.total_time
+    /// ```
+    pub fn total_time() -> Result<Duration, AnyException> {
+        gc_module()
+            .protect_send("total_time", &[])
+            .map(|nanoseconds| {
+                let nanoseconds = Integer::from(nanoseconds.value()).to_u64();
+
+                Duration::from_nanos(nanoseconds)
+            })
+    }
+
+    /// Registers a callback for internal GC events
+    ///
+    /// The callback is called from inside the garbage collector, so it must
+    /// not allocate Ruby objects, call Ruby methods or raise exceptions.
+    /// Only plain Rust code (counters, timers, logging to preallocated
+    /// buffers) is safe there.
+    ///
+    /// The hook stays active until the returned `GcEventHook` is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// use rutie::{GcEvent, GC, VM};
+    /// # VM::init();
+    ///
+    /// let starts = Arc::new(AtomicUsize::new(0));
+    /// let counter = starts.clone();
+    ///
+    /// let hook = GC::add_event_hook(&[GcEvent::Start, GcEvent::EndSweep], move |event| {
+    ///     if event == GcEvent::Start {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// });
+    ///
+    /// GC::start();
+    ///
+    /// assert!(starts.load(Ordering::Relaxed) >= 1);
+    ///
+    /// drop(hook);
+    /// ```
+    pub fn add_event_hook<F>(events: &[GcEvent], callback: F) -> GcEventHook
+    where
+        F: FnMut(GcEvent) + 'static,
+    {
+        let flags = events.iter().fold(0, |flags, event| flags | event.flag());
+
+        let callback: Box<Box<dyn FnMut(GcEvent)>> = Box::new(Box::new(callback));
+        let callback = Box::into_raw(callback);
+
+        let tracepoint = Box::new(tracepoint::new(
+            NilClass::new().value(),
+            flags,
+            gc_event_callback,
+            callback as *mut c_void,
+        ));
+
+        gc::register_address(&*tracepoint);
+        tracepoint::enable(*tracepoint);
+
+        GcEventHook {
+            tracepoint,
+            callback,
+        }
+    }
+
     /// Unregisters the objects address with the GC
     ///
     /// # Examples
@@ -254,3 +554,197 @@ impl GC {
         gc::unregister(object.value())
     }
 }
+
+fn gc_module() -> Module {
+    Module::from_existing("GC")
+}
+
+extern "C" fn gc_event_callback(tracepoint: Value, data: *mut c_void) {
+    let callback = unsafe { &mut *(data as *mut Box<dyn FnMut(GcEvent)>) };
+
+    if let Some(event) = GcEvent::from_flag(tracepoint::event_flag(tracepoint)) {
+        callback(event);
+    }
+}
+
+/// Internal GC events which can be observed with `GC::add_event_hook`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GcEvent {
+    /// A garbage collection has started
+    Start,
+    /// The marking phase has finished
+    EndMark,
+    /// The sweeping phase has finished
+    EndSweep,
+    /// The garbage collector was entered
+    Enter,
+    /// The garbage collector was exited
+    Exit,
+}
+
+impl GcEvent {
+    fn flag(self) -> EventFlag {
+        match self {
+            GcEvent::Start => RUBY_INTERNAL_EVENT_GC_START,
+            GcEvent::EndMark => RUBY_INTERNAL_EVENT_GC_END_MARK,
+            GcEvent::EndSweep => RUBY_INTERNAL_EVENT_GC_END_SWEEP,
+            GcEvent::Enter => RUBY_INTERNAL_EVENT_GC_ENTER,
+            GcEvent::Exit => RUBY_INTERNAL_EVENT_GC_EXIT,
+        }
+    }
+
+    fn from_flag(flag: EventFlag) -> Option<Self> {
+        match flag {
+            RUBY_INTERNAL_EVENT_GC_START => Some(GcEvent::Start),
+            RUBY_INTERNAL_EVENT_GC_END_MARK => Some(GcEvent::EndMark),
+            RUBY_INTERNAL_EVENT_GC_END_SWEEP => Some(GcEvent::EndSweep),
+            RUBY_INTERNAL_EVENT_GC_ENTER => Some(GcEvent::Enter),
+            RUBY_INTERNAL_EVENT_GC_EXIT => Some(GcEvent::Exit),
+            _ => None,
+        }
+    }
+}
+
+/// A registered GC event callback, see `GC::add_event_hook`
+///
+/// The hook is disabled and the callback is freed when this value is dropped.
+#[derive(Debug)]
+pub struct GcEventHook {
+    tracepoint: Box<Value>,
+    callback: *mut Box<dyn FnMut(GcEvent)>,
+}
+
+impl GcEventHook {
+    /// Temporarily stops delivering events to the callback
+    pub fn disable(&self) {
+        tracepoint::disable(*self.tracepoint);
+    }
+
+    /// Resumes delivering events to the callback
+    pub fn enable(&self) {
+        tracepoint::enable(*self.tracepoint);
+    }
+
+    /// Returns `true` if events are delivered to the callback
+    pub fn is_enabled(&self) -> bool {
+        tracepoint::is_enabled(*self.tracepoint)
+    }
+}
+
+impl Drop for GcEventHook {
+    fn drop(&mut self) {
+        tracepoint::disable(*self.tracepoint);
+        gc::unregister_address(&*self.tracepoint);
+
+        unsafe { drop(Box::from_raw(self.callback)) };
+    }
+}
+
+/// A snapshot of `GC.stat`, see `GC::stats`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GcStats {
+    pub count: usize,
+    pub heap_allocated_pages: usize,
+    pub heap_available_slots: usize,
+    pub heap_live_slots: usize,
+    pub heap_free_slots: usize,
+    pub heap_final_slots: usize,
+    pub heap_marked_slots: usize,
+    pub heap_eden_pages: usize,
+    pub total_allocated_pages: usize,
+    pub total_freed_pages: usize,
+    pub total_allocated_objects: usize,
+    pub total_freed_objects: usize,
+    pub malloc_increase_bytes: usize,
+    pub malloc_increase_bytes_limit: usize,
+    pub minor_gc_count: usize,
+    pub major_gc_count: usize,
+    pub compact_count: usize,
+    pub read_barrier_faults: usize,
+    pub total_moved_objects: usize,
+    pub remembered_wb_unprotected_objects: usize,
+    pub remembered_wb_unprotected_objects_limit: usize,
+    pub old_objects: usize,
+    pub old_objects_limit: usize,
+    pub oldmalloc_increase_bytes: usize,
+    pub oldmalloc_increase_bytes_limit: usize,
+    /// Milliseconds spent in GC (Ruby 3.1+)
+    pub time: Option<usize>,
+    /// Milliseconds spent marking (Ruby 3.3+)
+    pub marking_time: Option<usize>,
+    /// Milliseconds spent sweeping (Ruby 3.3+)
+    pub sweeping_time: Option<usize>,
+    /// Removed in Ruby 3.4
+    pub heap_sorted_length: Option<usize>,
+    /// Removed in Ruby 3.4
+    pub heap_allocatable_pages: Option<usize>,
+    /// Removed in Ruby 3.4
+    pub heap_tomb_pages: Option<usize>,
+    /// Ruby 3.4+
+    pub heap_empty_pages: Option<usize>,
+    /// Ruby 3.4+
+    pub heap_allocatable_slots: Option<usize>,
+    /// Ruby 3.3+
+    pub weak_references_count: Option<usize>,
+    /// Ruby 3.3+
+    pub retained_weak_references_count: Option<usize>,
+    values: HashMap<String, usize>,
+}
+
+impl GcStats {
+    fn from_values(values: HashMap<String, usize>) -> Self {
+        let optional = |key: &str| values.get(key).copied();
+        let required = |key: &str| optional(key).unwrap_or(0);
+
+        GcStats {
+            count: required("count"),
+            heap_allocated_pages: required("heap_allocated_pages"),
+            heap_available_slots: required("heap_available_slots"),
+            heap_live_slots: required("heap_live_slots"),
+            heap_free_slots: required("heap_free_slots"),
+            heap_final_slots: required("heap_final_slots"),
+            heap_marked_slots: required("heap_marked_slots"),
+            heap_eden_pages: required("heap_eden_pages"),
+            total_allocated_pages: required("total_allocated_pages"),
+            total_freed_pages: required("total_freed_pages"),
+            total_allocated_objects: required("total_allocated_objects"),
+            total_freed_objects: required("total_freed_objects"),
+            malloc_increase_bytes: required("malloc_increase_bytes"),
+            malloc_increase_bytes_limit: required("malloc_increase_bytes_limit"),
+            minor_gc_count: required("minor_gc_count"),
+            major_gc_count: required("major_gc_count"),
+            compact_count: required("compact_count"),
+            read_barrier_faults: required("read_barrier_faults"),
+            total_moved_objects: required("total_moved_objects"),
+            remembered_wb_unprotected_objects: required("remembered_wb_unprotected_objects"),
+            remembered_wb_unprotected_objects_limit: required(
+                "remembered_wb_unprotected_objects_limit",
+            ),
+            old_objects: required("old_objects"),
+            old_objects_limit: required("old_objects_limit"),
+            oldmalloc_increase_bytes: required("oldmalloc_increase_bytes"),
+            oldmalloc_increase_bytes_limit: required("oldmalloc_increase_bytes_limit"),
+            time: optional("time"),
+            marking_time: optional("marking_time"),
+            sweeping_time: optional("sweeping_time"),
+            heap_sorted_length: optional("heap_sorted_length"),
+            heap_allocatable_pages: optional("heap_allocatable_pages"),
+            heap_tomb_pages: optional("heap_tomb_pages"),
+            heap_empty_pages: optional("heap_empty_pages"),
+            heap_allocatable_slots: optional("heap_allocatable_slots"),
+            weak_references_count: optional("weak_references_count"),
+            retained_weak_references_count: optional("retained_weak_references_count"),
+            values,
+        }
+    }
+
+    /// Returns the value of any `GC.stat` key, including keys without a field
+    pub fn get(&self, key: &str) -> Option<usize> {
+        self.values.get(key).copied()
+    }
+
+    /// Returns every key and value reported by `GC.stat`
+    pub fn values(&self) -> &HashMap<String, usize> {
+        &self.values
+    }
+}
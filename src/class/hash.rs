@@ -1,9 +1,16 @@
-use std::{convert::From, default::Default};
+use std::{
+    collections::HashMap,
+    convert::{From, TryFrom},
+    default::Default,
+    hash::{BuildHasher, Hash as StdHash},
+    ops::ControlFlow,
+};
 
 use crate::{
     binding::hash,
     types::{Value, ValueType},
-    AnyObject, Object, VerifiedObject,
+    AnyException, AnyObject, Array, ArrayElement, ArrayIterator, NilClass, Object, Proc,
+    VerifiedObject,
 };
 
 /// `Hash`
@@ -245,11 +252,488 @@ impl Hash {
     ///
     /// doubled_values == [2, 4]
     /// ```
-    pub fn each<F>(&self, closure: F)
+    pub fn each<F>(&self, mut closure: F)
     where
         F: FnMut(AnyObject, AnyObject),
     {
-        hash::each(self.value(), closure);
+        hash::each(self.value(), |key, value| {
+            closure(key, value);
+
+            ControlFlow::Continue(())
+        });
+    }
+
+    /// Runs a closure for each `key` and `value` pair until it returns `ControlFlow::Break`.
+    ///
+    /// Returns the `ControlFlow::Break` value which stopped the iteration, or
+    /// `ControlFlow::Continue(())` if all pairs were visited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::ControlFlow;
+    ///
+    /// use rutie::{Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    ///
+    /// hash.store(Symbol::new("first_key"), Fixnum::new(1));
+    /// hash.store(Symbol::new("second_key"), Fixnum::new(-2));
+    /// hash.store(Symbol::new("third_key"), Fixnum::new(3));
+    ///
+    /// let mut visited = 0;
+    ///
+    /// let result = hash.try_each(|key, value| {
+    ///     visited += 1;
+    ///
+    ///     if value.try_convert_to::<Fixnum>().unwrap().to_i64() < 0 {
+    ///         ControlFlow::Break(key.try_convert_to::<Symbol>().unwrap().to_string())
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(result, ControlFlow::Break("second_key".to_string()));
+    /// assert_eq!(visited, 2);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = {
+    ///   first_key: 1,
+    ///   second_key: -2,
+    ///   third_key: 3
+    /// }
+    ///
+    /// result = hash.each do |key, value|
+    ///   break key.to_s if value < 0
+    /// end
+    ///
+    /// result == "second_key"
+    /// ```
+    pub fn try_each<B, F>(&self, mut closure: F) -> ControlFlow<B>
+    where
+        F: FnMut(AnyObject, AnyObject) -> ControlFlow<B>,
+    {
+        let mut result = ControlFlow::Continue(());
+
+        hash::each(self.value(), |key, value| match closure(key, value) {
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            ControlFlow::Break(output) => {
+                result = ControlFlow::Break(output);
+
+                ControlFlow::Break(())
+            }
+        });
+
+        result
+    }
+
+    /// Returns an iterator over the `key` and `value` pairs in insertion order.
+    ///
+    /// The pairs are copied when the iterator is created, so changes to the hash do not
+    /// affect it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    ///
+    /// hash.store(Symbol::new("first_key"), Fixnum::new(1));
+    /// hash.store(Symbol::new("second_key"), Fixnum::new(2));
+    ///
+    /// let sum: i64 = hash
+    ///     .iter()
+    ///     .map(|(_key, value)| value.try_convert_to::<Fixnum>().unwrap().to_i64())
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 3);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = {
+    ///   first_key: 1,
+    ///   second_key: 2
+    /// }
+    ///
+    /// hash.each.sum { |_key, value| value } == 3
+    /// ```
+    pub fn iter(&self) -> HashIterator {
+        HashIterator::new(self)
+    }
+
+    /// Returns a new array populated with the keys of the hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    ///
+    /// hash.store(Symbol::new("first_key"), Fixnum::new(1));
+    /// hash.store(Symbol::new("second_key"), Fixnum::new(2));
+    ///
+    /// let keys = hash.keys();
+    ///
+    /// assert_eq!(keys.length(), 2);
+    /// assert_eq!(keys.at(0).try_convert_to::<Symbol>(), Ok(Symbol::new("first_key")));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = {
+    ///   first_key: 1,
+    ///   second_key: 2
+    /// }
+    ///
+    /// hash.keys == [:first_key, :second_key]
+    /// ```
+    pub fn keys(&self) -> Array {
+        let result = unsafe { self.send("keys", &[]) };
+
+        Array::from(result.value())
+    }
+
+    /// Returns a new array populated with the values of the hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Hash, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    ///
+    /// hash.store(Symbol::new("first_key"), Fixnum::new(1));
+    /// hash.store(Symbol::new("second_key"), Fixnum::new(2));
+    ///
+    /// assert_eq!(hash.values().to_vec::<i64>(), Ok(vec![1, 2]));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = {
+    ///   first_key: 1,
+    ///   second_key: 2
+    /// }
+    ///
+    /// hash.values == [1, 2]
+    /// ```
+    pub fn values(&self) -> Array {
+        let result = unsafe { self.send("values", &[]) };
+
+        Array::from(result.value())
+    }
+
+    /// Returns `true` if the hash contains `key`.
+    ///
+    /// Unlike `at`, it tells apart a missing key from a key associated with `nil`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Hash, NilClass, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    ///
+    /// hash.store(Symbol::new("key"), NilClass::new());
+    ///
+    /// assert!(hash.contains_key(&Symbol::new("key")));
+    /// assert!(!hash.contains_key(&Symbol::new("missing")));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = { key: nil }
+    ///
+    /// hash.key?(:key) == true
+    /// hash.key?(:missing) == false
+    /// ```
+    pub fn contains_key<K: Object>(&self, key: &K) -> bool {
+        let result = unsafe { self.send("key?", &[key.to_any_object()]) };
+
+        result.value().is_true()
+    }
+
+    /// Retrieves the value stored at `key`, or `default` if the hash does not contain it.
+    ///
+    /// The default value and the default proc of the hash are not used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    ///
+    /// hash.store(Symbol::new("key"), Fixnum::new(1));
+    /// hash.set_default_value(Fixnum::new(0));
+    ///
+    /// let value = hash.fetch(&Symbol::new("key"), Fixnum::new(-1));
+    /// let missing = hash.fetch(&Symbol::new("missing"), Fixnum::new(-1));
+    ///
+    /// assert_eq!(value.try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert_eq!(missing.try_convert_to::<Fixnum>(), Ok(Fixnum::new(-1)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = Hash.new(0)
+    /// hash[:key] = 1
+    ///
+    /// hash.fetch(:key, -1) == 1
+    /// hash.fetch(:missing, -1) == -1
+    /// ```
+    pub fn fetch<K: Object, D: Object>(&self, key: &K, default: D) -> AnyObject {
+        let result = hash::lookup(self.value(), key.value(), default.value());
+
+        AnyObject::from(result)
+    }
+
+    /// Returns the value returned by `at` for a missing key, `nil` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    ///
+    /// assert!(hash.default_value().is_nil());
+    ///
+    /// hash.set_default_value(Fixnum::new(0));
+    ///
+    /// assert_eq!(hash.default_value().try_convert_to::<Fixnum>(), Ok(Fixnum::new(0)));
+    /// assert_eq!(hash.at(&Symbol::new("missing")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(0)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = {}
+    /// hash.default = 0
+    ///
+    /// hash.default == 0
+    /// hash[:missing] == 0
+    /// ```
+    pub fn default_value(&self) -> AnyObject {
+        unsafe { self.send("default", &[]) }
+    }
+
+    /// Sets the value returned by `at` for a missing key.
+    ///
+    /// Removes the default proc of the hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    ///
+    /// hash.set_default_value(Fixnum::new(0));
+    ///
+    /// assert_eq!(hash.at(&Symbol::new("missing")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(0)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = {}
+    /// hash.default = 0
+    ///
+    /// hash[:missing] == 0
+    /// ```
+    pub fn set_default_value<T: Object>(&mut self, value: T) {
+        unsafe { self.send("default=", &[value.to_any_object()]) };
+    }
+
+    /// Returns the proc called by `at` for a missing key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Hash, Object, VM};
+    /// # VM::init();
+    ///
+    /// assert!(Hash::new().default_proc().is_none());
+    ///
+    /// let hash = VM::eval("Hash.new { |hash, key| key.to_s }").unwrap()
+    ///     .try_convert_to::<Hash>().unwrap();
+    ///
+    /// assert!(hash.default_proc().is_some());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// {}.default_proc == nil
+    ///
+    /// Hash.new { |hash, key| key.to_s }.default_proc.is_a?(Proc)
+    /// ```
+    pub fn default_proc(&self) -> Option<Proc> {
+        let result = unsafe { self.send("default_proc", &[]) };
+
+        if result.is_nil() {
+            None
+        } else {
+            Some(Proc::from(result.value()))
+        }
+    }
+
+    /// Sets the proc called with the hash and the key by `at` for a missing key.
+    ///
+    /// `None` removes the default proc. Removes the default value of the hash.
+    /// Returns `Err` with a `TypeError` for a lambda which does not accept two arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Hash, Object, Proc, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    /// let default_proc = VM::eval("proc { |hash, key| hash[key] = key.to_s }").unwrap()
+    ///     .try_convert_to::<Proc>().unwrap();
+    ///
+    /// hash.set_default_proc(Some(&default_proc)).unwrap();
+    ///
+    /// assert_eq!(hash.at(&Symbol::new("key")).try_convert_to::<RString>().unwrap().to_str(), "key");
+    /// assert_eq!(hash.length(), 1);
+    ///
+    /// let lambda = VM::eval("lambda { |key| key }").unwrap().try_convert_to::<Proc>().unwrap();
+    ///
+    /// assert!(hash.set_default_proc(Some(&lambda)).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = {}
+    /// hash.default_proc = proc { |hash, key| hash[key] = key.to_s }
+    ///
+    /// hash[:key] == "key"
+    /// hash.length == 1
+    /// ```
+    pub fn set_default_proc(&mut self, default_proc: Option<&Proc>) -> Result<(), AnyException> {
+        let argument = match default_proc {
+            Some(default_proc) => default_proc.to_any_object(),
+            None => NilClass::new().to_any_object(),
+        };
+
+        self.protect_send("default_proc=", &[argument]).map(|_| ())
+    }
+
+    /// Makes the hash compare keys by their identity instead of `eql?`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Hash, RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    ///
+    /// hash.compare_by_identity();
+    ///
+    /// hash.store(RString::new_utf8("key"), Fixnum::new(1));
+    ///
+    /// assert!(hash.is_compare_by_identity());
+    /// assert!(!hash.contains_key(&RString::new_utf8("key")));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = {}
+    /// hash.compare_by_identity
+    ///
+    /// hash["key".dup] = 1
+    ///
+    /// hash.compare_by_identity? == true
+    /// hash.key?("key".dup) == false
+    /// ```
+    pub fn compare_by_identity(&mut self) {
+        unsafe { self.send("compare_by_identity", &[]) };
+    }
+
+    /// Returns `true` if the hash compares keys by their identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Hash, VM};
+    /// # VM::init();
+    ///
+    /// assert!(!Hash::new().is_compare_by_identity());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// {}.compare_by_identity? == false
+    /// ```
+    pub fn is_compare_by_identity(&self) -> bool {
+        let result = unsafe { self.send("compare_by_identity?", &[]) };
+
+        result.value().is_true()
+    }
+
+    /// Returns a new hash with the pairs of `other` added to the pairs of `self`.
+    ///
+    /// Values of `other` replace the values of duplicate keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    /// let mut other = Hash::new();
+    ///
+    /// hash.store(Symbol::new("first_key"), Fixnum::new(1));
+    /// hash.store(Symbol::new("second_key"), Fixnum::new(2));
+    /// other.store(Symbol::new("second_key"), Fixnum::new(3));
+    ///
+    /// let merged = hash.merge(&other);
+    ///
+    /// assert_eq!(merged.length(), 2);
+    /// assert_eq!(merged.at(&Symbol::new("second_key")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(3)));
+    /// assert_eq!(hash.at(&Symbol::new("second_key")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = { first_key: 1, second_key: 2 }
+    /// other = { second_key: 3 }
+    ///
+    /// merged = hash.merge(other)
+    ///
+    /// merged == { first_key: 1, second_key: 3 }
+    /// ```
+    pub fn merge(&self, other: &Hash) -> Hash {
+        let result = unsafe { self.send("merge", &[other.to_any_object()]) };
+
+        Hash::from(result.value())
     }
 }
 
@@ -308,10 +792,126 @@ impl PartialEq for Hash {
     }
 }
 
+impl<K, V, S> From<HashMap<K, V, S>> for Hash
+where
+    K: ArrayElement,
+    V: ArrayElement,
+{
+    fn from(map: HashMap<K, V, S>) -> Self {
+        let mut hash = Hash::new();
+
+        for (key, value) in map.iter() {
+            hash.store(key.to_element(), value.to_element());
+        }
+
+        hash
+    }
+}
+
+/// Converts a `Hash` to a `HashMap`.
+///
+/// Keys and values are converted with the `ArrayElement` trait. Returns `Err` with the
+/// error of the first key or value which cannot be converted.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use std::convert::TryFrom;
+///
+/// use rutie::{Hash, VM};
+/// # VM::init();
+///
+/// let mut map = HashMap::new();
+///
+/// map.insert("first".to_string(), 1);
+/// map.insert("second".to_string(), 2);
+///
+/// let hash = Hash::from(map.clone());
+///
+/// assert_eq!(hash.length(), 2);
+/// assert_eq!(HashMap::<String, i64>::try_from(hash), Ok(map));
+/// ```
+impl<K, V, S> TryFrom<Hash> for HashMap<K, V, S>
+where
+    K: ArrayElement + Eq + StdHash,
+    V: ArrayElement,
+    S: BuildHasher + Default,
+{
+    type Error = AnyException;
+
+    fn try_from(hash: Hash) -> Result<Self, Self::Error> {
+        let mut map = HashMap::with_capacity_and_hasher(hash.length(), S::default());
+
+        for (key, value) in hash.iter() {
+            map.insert(K::from_element(&key)?, V::from_element(&value)?);
+        }
+
+        Ok(map)
+    }
+}
+
+pub struct HashIterator {
+    pairs: ArrayIterator,
+}
+
+impl HashIterator {
+    fn new(hash: &Hash) -> HashIterator {
+        let pairs = unsafe { hash.send("to_a", &[]) };
+
+        HashIterator {
+            pairs: Array::from(pairs.value()).into_iter(),
+        }
+    }
+}
+
+impl Iterator for HashIterator {
+    type Item = (AnyObject, AnyObject);
+
+    fn next(&mut self) -> Option<(AnyObject, AnyObject)> {
+        self.pairs.next().map(|pair| {
+            let pair = Array::from(pair.value());
+
+            (pair.at(0), pair.at(1))
+        })
+    }
+}
+
+/// Allows Hashes to be iterable in Rust.
+///
+/// # Examples
+///
+/// ```
+/// use rutie::{Fixnum, Hash, Object, Symbol, VM};
+/// # VM::init();
+///
+/// let mut hash = Hash::new();
+///
+/// hash.store(Symbol::new("first_key"), Fixnum::new(1));
+/// hash.store(Symbol::new("second_key"), Fixnum::new(2));
+///
+/// let mut keys = Vec::new();
+///
+/// for (key, _value) in hash {
+///     keys.push(key.try_convert_to::<Symbol>().unwrap().to_string());
+/// }
+///
+/// assert_eq!(keys, vec!["first_key", "second_key"]);
+/// ```
+impl IntoIterator for Hash {
+    type Item = (AnyObject, AnyObject);
+    type IntoIter = HashIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        HashIterator::new(&self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::{Fixnum, Hash, Object, Symbol};
     use rb_sys_test_helpers::ruby_test;
+    use std::ops::ControlFlow;
 
     #[ruby_test]
     fn test_hash_each() {
@@ -342,4 +942,34 @@ mod tests {
 
         assert_eq!(counter, len);
     }
+
+    #[ruby_test]
+    fn test_hash_try_each_stops_early() {
+        let mut hash = Hash::new();
+
+        for i in 0..10 {
+            hash.store(Symbol::new(&format!("key_{}", i)), Fixnum::new(i));
+        }
+
+        let mut counter: i64 = 0;
+
+        let result = hash.try_each(|_k, v| {
+            counter += 1;
+
+            let value = v.try_convert_to::<Fixnum>().unwrap().to_i64();
+
+            if value == 4 {
+                ControlFlow::Break(value)
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+
+        assert_eq!(result, ControlFlow::Break(4));
+        assert_eq!(counter, 5);
+
+        let result: ControlFlow<()> = hash.try_each(|_k, _v| ControlFlow::Continue(()));
+
+        assert_eq!(result, ControlFlow::Continue(()));
+    }
 }
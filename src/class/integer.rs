@@ -3,7 +3,7 @@ use std::convert::From;
 use crate::{
     binding::fixnum,
     types::{Value, ValueType},
-    AnyObject, Fixnum, Object, VerifiedObject,
+    AnyObject, Fixnum, Numeric, Object, VerifiedObject,
 };
 
 /// `Integer`
@@ -212,6 +212,8 @@ impl Object for Integer {
     }
 }
 
+impl Numeric for Integer {}
+
 impl VerifiedObject for Integer {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         let ty = object.value().ty();
@@ -0,0 +1,328 @@
+use std::{
+    cmp,
+    convert::From,
+    io::{self, BufRead, Read, Write},
+};
+
+#[cfg(unix)]
+use crate::types::RawFd;
+use crate::{
+    binding::{io as rio, string},
+    types::{Value, ValueType},
+    AnyException, AnyObject, Class, Exception, Integer, Object, RString, VerifiedObject, VM,
+};
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// `IO`
+///
+/// Wraps a Ruby `IO` or any object following its duck type, like `StringIO` or
+/// `Tempfile`, and implements `std::io::Read` and `Write` on top of it.
+///
+/// Reads go through `readpartial` (or `read` for objects that lack it) and writes
+/// through `write`, so data buffered on the Ruby side is never skipped. For real
+/// files and sockets those methods release the GVL while they block and cooperate
+/// with a `Fiber` scheduler.
+///
+/// `IO` itself does not buffer, use `IO::reader` for `BufRead`.
+#[derive(Debug)]
+#[repr(C)]
+pub struct IO {
+    value: Value,
+}
+
+impl IO {
+    /// Returns the file descriptor of a real file or socket.
+    ///
+    /// Returns `None` for objects that are not backed by a file descriptor, like
+    /// `StringIO`, or when the IO is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{IO, Object, VM};
+    /// # VM::init();
+    ///
+    /// let stderr = IO::from(VM::eval("STDERR").unwrap().value());
+    ///
+    /// assert_eq!(stderr.raw_fd(), Some(2));
+    ///
+    /// let string_io = IO::from(VM::eval("require 'stringio'; StringIO.new").unwrap().value());
+    ///
+    /// assert_eq!(string_io.raw_fd(), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// STDERR.fileno == 2
+    /// ```
+    #[cfg(unix)]
+    pub fn raw_fd(&self) -> Option<RawFd> {
+        let io = AnyObject::from(rio::check_io(self.value()));
+
+        if io.is_nil() {
+            return None;
+        }
+
+        match io.protect_send("fileno", &[]) {
+            Ok(fileno) if !fileno.is_nil() => Some(Integer::from(fileno.value()).to_i64() as RawFd),
+            _ => None,
+        }
+    }
+
+    /// Returns a reader implementing `BufRead` on top of this IO.
+    ///
+    /// Bytes read ahead are kept in the reader, so use the same reader for
+    /// consecutive reads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::BufRead;
+    ///
+    /// use rutie::{IO, Object, VM};
+    /// # VM::init();
+    ///
+    /// let string_io = VM::eval("require 'stringio'; StringIO.new(\"a\\nb\\n\")").unwrap();
+    /// let io = IO::from(string_io.value());
+    ///
+    /// let lines: Vec<String> = io.reader().lines().map(Result::unwrap).collect();
+    ///
+    /// assert_eq!(lines, vec!["a", "b"]);
+    /// ```
+    pub fn reader(&self) -> IOReader {
+        IOReader {
+            io: IO::from(self.value()),
+            buffer: Vec::new(),
+            position: 0,
+        }
+    }
+
+    fn read_chunk(&self, length: usize) -> io::Result<Option<RString>> {
+        let method = if self.respond_to("readpartial") {
+            "readpartial"
+        } else {
+            "read"
+        };
+
+        let length = Integer::from(length as u64).to_any_object();
+
+        match self.protect_send(method, &[length]) {
+            Ok(chunk) if chunk.is_nil() => Ok(None),
+            Ok(chunk) => Ok(Some(RString::from(chunk.value()))),
+            Err(ref exception) if Class::from_existing("EOFError").case_equals(exception) => {
+                Ok(None)
+            }
+            Err(exception) => Err(Self::to_io_error(exception)),
+        }
+    }
+
+    fn to_io_error(exception: AnyException) -> io::Error {
+        if Class::from_existing("SystemCallError").case_equals(&exception) {
+            if let Ok(errno) = exception.protect_send("errno", &[]) {
+                return io::Error::from_raw_os_error(Integer::from(errno.value()).to_i64() as i32);
+            }
+        }
+
+        io::Error::new(io::ErrorKind::Other, exception.message())
+    }
+
+    fn protect<F>(func: F) -> io::Result<AnyObject>
+    where
+        F: FnMut() -> AnyObject,
+    {
+        VM::protect(func).map_err(|_| {
+            let output = VM::error_info().unwrap();
+
+            // error cleanup
+            VM::clear_error_info();
+
+            Self::to_io_error(output)
+        })
+    }
+}
+
+impl Read for IO {
+    /// Reads the next chunk of bytes, returning `Ok(0)` at the end of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Read;
+    ///
+    /// use rutie::{IO, Object, VM};
+    /// # VM::init();
+    ///
+    /// let string_io = VM::eval("require 'stringio'; StringIO.new('hello')").unwrap();
+    /// let mut io = IO::from(string_io.value());
+    ///
+    /// let mut contents = String::new();
+    /// io.read_to_string(&mut contents).unwrap();
+    ///
+    /// assert_eq!(contents, "hello");
+    /// ```
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        // Both `readpartial` and `read` return at most `buf.len()` bytes
+        let chunk = match self.read_chunk(buf.len())? {
+            Some(chunk) => chunk,
+            None => return Ok(0),
+        };
+        let bytes = chunk.to_bytes_unchecked();
+        let length = cmp::min(bytes.len(), buf.len());
+
+        buf[..length].copy_from_slice(&bytes[..length]);
+
+        Ok(length)
+    }
+}
+
+impl Write for IO {
+    /// Writes the bytes as a binary string, returning the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use rutie::{IO, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string_io = VM::eval("require 'stringio'; StringIO.new").unwrap();
+    /// let mut io = IO::from(string_io.value());
+    ///
+    /// write!(io, "{} + {} = {}", 1, 2, 3).unwrap();
+    /// io.flush().unwrap();
+    ///
+    /// let contents = unsafe { string_io.send("string", &[]) };
+    ///
+    /// assert_eq!(RString::from(contents.value()).to_str(), "1 + 2 = 3");
+    /// ```
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let value = self.value();
+        let bytes = string::new_binary(buf);
+
+        let written = Self::protect(|| AnyObject::from(rio::write(value, bytes)))?;
+
+        if written.is_nil() {
+            Ok(buf.len())
+        } else {
+            Ok(Integer::from(written.value()).to_u64() as usize)
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let value = self.value();
+
+        Self::protect(|| AnyObject::from(rio::flush(value))).map(|_| ())
+    }
+}
+
+/// A buffered reader over an `IO`, see `IO::reader`
+///
+/// Unlike `IO` this is a Rust-only type, converting it back with `into_inner`
+/// drops the bytes read ahead.
+#[derive(Debug)]
+pub struct IOReader {
+    io: IO,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl IOReader {
+    /// Returns the underlying `IO`.
+    pub fn get_ref(&self) -> &IO {
+        &self.io
+    }
+
+    /// Returns the underlying `IO`, dropping the bytes read ahead.
+    pub fn into_inner(self) -> IO {
+        self.io
+    }
+}
+
+impl Read for IOReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position >= self.buffer.len() && buf.len() >= CHUNK_SIZE {
+            // Skip our buffer entirely for large reads
+            return self.io.read(buf);
+        }
+
+        let available = self.fill_buf()?;
+        let length = cmp::min(available.len(), buf.len());
+
+        buf[..length].copy_from_slice(&available[..length]);
+        self.consume(length);
+
+        Ok(length)
+    }
+}
+
+impl BufRead for IOReader {
+    /// Returns the bytes read ahead, reading the next chunk when they are used up.
+    ///
+    /// See `IO::reader` for an example.
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.position >= self.buffer.len() {
+            self.buffer.clear();
+            self.position = 0;
+
+            if let Some(chunk) = self.io.read_chunk(CHUNK_SIZE)? {
+                self.buffer.extend_from_slice(chunk.to_bytes_unchecked());
+            }
+        }
+
+        Ok(&self.buffer[self.position..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.position = cmp::min(self.position + amt, self.buffer.len());
+    }
+}
+
+impl From<Value> for IO {
+    fn from(value: Value) -> Self {
+        IO { value }
+    }
+}
+
+impl From<IO> for Value {
+    fn from(val: IO) -> Self {
+        val.value
+    }
+}
+
+impl From<IO> for AnyObject {
+    fn from(val: IO) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for IO {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for IO {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::File
+            || object.respond_to("read")
+            || object.respond_to("write")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to IO"
+    }
+}
+
+impl PartialEq for IO {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
@@ -0,0 +1,447 @@
+use std::{convert::From, str};
+
+use crate::{
+    binding::{regexp, string},
+    types::{Value, ValueType},
+    AnyObject, Array, Integer, Object, RString, Regexp, VerifiedObject,
+};
+
+/// `MatchData`
+///
+/// Returned by `Regexp::match_at`. Offsets are byte offsets into `string()`, so
+/// they can be used to slice the bytes of the matched string directly.
+#[derive(Debug)]
+#[repr(C)]
+pub struct MatchData {
+    value: Value,
+}
+
+impl MatchData {
+    /// Returns the frozen copy of the string that was matched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("b", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("abc"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.string().to_str(), "abc");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /b/.match("abc").string == "abc"
+    /// ```
+    pub fn string(&self) -> RString {
+        let string = unsafe { self.send("string", &[]) };
+
+        RString::from(string.value())
+    }
+
+    /// Returns the regexp that produced the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("b", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("abc"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.regexp(), regexp);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /b/.match("abc").regexp == /b/
+    /// ```
+    pub fn regexp(&self) -> Regexp {
+        let regexp = unsafe { self.send("regexp", &[]) };
+
+        Regexp::from(regexp.value())
+    }
+
+    /// Returns the number of groups, including the whole match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("(a)(b)?", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("a"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.length(), 3);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /(a)(b)?/.match("a").size == 3
+    /// ```
+    pub fn length(&self) -> usize {
+        let size = unsafe { self.send("size", &[]) };
+
+        Integer::from(size.value()).to_u64() as usize
+    }
+
+    /// Returns the names of the named groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("(?<year>\\d+)-(?<month>\\d+)", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("2020-02"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.names(), vec!["year", "month"]);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /(?<year>\d+)-(?<month>\d+)/.match("2020-02").names == ["year", "month"]
+    /// ```
+    pub fn names(&self) -> Vec<String> {
+        let names = unsafe { self.send("names", &[]) };
+
+        Array::from(names.value())
+            .into_iter()
+            .map(|name| RString::from(name.value()).to_string())
+            .collect()
+    }
+
+    /// Returns the start and end byte offsets of the `nth` group.
+    ///
+    /// Group `0` is the whole match. Returns `None` when the group does not
+    /// exist or did not take part in the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("(ü)(x)?", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("aüb"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.byte_offset(0), Some((1, 3)));
+    /// assert_eq!(match_data.byte_offset(1), Some((1, 3)));
+    /// assert_eq!(match_data.byte_offset(2), None);
+    /// assert_eq!(match_data.byte_offset(3), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// match_data = /(ü)(x)?/.match("aüb")
+    ///
+    /// match_data.byteoffset(0) == [1, 3]
+    /// match_data.byteoffset(2) == [nil, nil]
+    /// ```
+    pub fn byte_offset(&self, nth: usize) -> Option<(usize, usize)> {
+        self.group_byte_offset(Integer::from(nth as u64).to_any_object())
+    }
+
+    /// Returns the start and end byte offsets of the group called `name`.
+    ///
+    /// Returns `None` when there is no such group or it did not take part in
+    /// the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("(?<word>\\w+)", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("  hi"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.named_byte_offset("word"), Some((2, 4)));
+    /// assert_eq!(match_data.named_byte_offset("other"), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /(?<word>\w+)/.match("  hi").byteoffset(:word) == [2, 4]
+    /// ```
+    pub fn named_byte_offset(&self, name: &str) -> Option<(usize, usize)> {
+        self.group_byte_offset(RString::new_utf8(name).to_any_object())
+    }
+
+    /// Returns the `nth` group as a new `RString` in the encoding of the
+    /// matched string.
+    ///
+    /// Returns `None` when the group does not exist or did not take part in the
+    /// match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("(\\d+)-(\\d+)?", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("10-"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.capture(1), Some(RString::new_utf8("10")));
+    /// assert_eq!(match_data.capture(2), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// match_data = /(\d+)-(\d+)?/.match("10-")
+    ///
+    /// match_data[1] == "10"
+    /// match_data[2] == nil
+    /// ```
+    pub fn capture(&self, nth: usize) -> Option<RString> {
+        if nth > i32::MAX as usize {
+            return None;
+        }
+
+        let capture = regexp::nth_match(nth as i32, self.value());
+
+        if capture.is_nil() {
+            None
+        } else {
+            Some(RString::from(capture))
+        }
+    }
+
+    /// Returns the group called `name` as a new `RString` in the encoding of
+    /// the matched string.
+    ///
+    /// Returns `None` when there is no such group or it did not take part in
+    /// the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("(?<key>\\w+)=(?<value>\\w+)", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("a=b"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.named_capture("value"), Some(RString::new_utf8("b")));
+    /// assert_eq!(match_data.named_capture("other"), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /(?<key>\w+)=(?<value>\w+)/.match("a=b")[:value] == "b"
+    /// ```
+    pub fn named_capture(&self, name: &str) -> Option<RString> {
+        let name = RString::new_utf8(name).to_any_object();
+
+        match self.protect_send("[]", &[name]) {
+            Ok(capture) if !capture.is_nil() => Some(RString::from(capture.value())),
+            _ => None,
+        }
+    }
+
+    /// Returns the `nth` group as a `&str` borrowed from the matched string.
+    ///
+    /// Returns `None` when the group does not exist, did not take part in the
+    /// match, or is not valid UTF-8. Use `capture` for strings in other
+    /// encodings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("(\\w+) (\\w+)", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("hello world"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.capture_str(0), Some("hello world"));
+    /// assert_eq!(match_data.capture_str(2), Some("world"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /(\w+) (\w+)/.match("hello world")[2] == "world"
+    /// ```
+    pub fn capture_str(&self, nth: usize) -> Option<&str> {
+        self.byte_offset(nth).and_then(|offset| self.str_at(offset))
+    }
+
+    /// Returns the group called `name` as a `&str` borrowed from the matched
+    /// string.
+    ///
+    /// Returns `None` when there is no such group, it did not take part in the
+    /// match, or it is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("(?<name>\\w+)@", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("user@host"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.named_capture_str("name"), Some("user"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /(?<name>\w+)@/.match("user@host")[:name] == "user"
+    /// ```
+    pub fn named_capture_str(&self, name: &str) -> Option<&str> {
+        self.named_byte_offset(name)
+            .and_then(|offset| self.str_at(offset))
+    }
+
+    /// Returns the part of the string before the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("b+", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("aabbcc"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.pre_match().to_str(), "aa");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /b+/.match("aabbcc").pre_match == "aa"
+    /// ```
+    pub fn pre_match(&self) -> RString {
+        RString::from(regexp::pre_match(self.value()))
+    }
+
+    /// Returns the part of the string after the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("b+", 0).unwrap();
+    /// let match_data = regexp.match_at(&RString::new_utf8("aabbcc"), 0).unwrap();
+    ///
+    /// assert_eq!(match_data.post_match().to_str(), "cc");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /b+/.match("aabbcc").post_match == "cc"
+    /// ```
+    pub fn post_match(&self) -> RString {
+        RString::from(regexp::post_match(self.value()))
+    }
+
+    fn group_byte_offset(&self, group: AnyObject) -> Option<(usize, usize)> {
+        // `MatchData#byteoffset` is only available since Ruby 3.2
+        if self.respond_to("byteoffset") {
+            let offset = self.protect_send("byteoffset", &[group]).ok()?;
+            let offset = Array::from(offset.value());
+
+            return Some((
+                Self::to_offset(offset.at(0))?,
+                Self::to_offset(offset.at(1))?,
+            ));
+        }
+
+        let begin = self.protect_send("begin", &[group.clone()]).ok()?;
+        let end = self.protect_send("end", &[group]).ok()?;
+
+        Some((
+            self.char_to_byte_offset(Self::to_offset(begin)?),
+            self.char_to_byte_offset(Self::to_offset(end)?),
+        ))
+    }
+
+    fn char_to_byte_offset(&self, chars: usize) -> usize {
+        let arguments = [
+            Integer::new(0).to_any_object(),
+            Integer::from(chars as u64).to_any_object(),
+        ];
+        let prefix = unsafe { self.string().send("[]", &arguments) };
+
+        RString::from(prefix.value()).bytesize() as usize
+    }
+
+    fn to_offset(offset: AnyObject) -> Option<usize> {
+        if offset.is_nil() {
+            None
+        } else {
+            Some(Integer::from(offset.value()).to_u64() as usize)
+        }
+    }
+
+    fn str_at(&self, (begin, end): (usize, usize)) -> Option<&str> {
+        // The matched string is frozen and referenced by the match, so it
+        // lives at least as long as `self`.
+        let bytes = string::value_to_bytes_unchecked(self.string().value());
+
+        bytes
+            .get(begin..end)
+            .and_then(|bytes| str::from_utf8(bytes).ok())
+    }
+}
+
+impl From<Value> for MatchData {
+    fn from(value: Value) -> Self {
+        MatchData { value }
+    }
+}
+
+impl From<MatchData> for Value {
+    fn from(val: MatchData) -> Self {
+        val.value
+    }
+}
+
+impl From<MatchData> for AnyObject {
+    fn from(val: MatchData) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for MatchData {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for MatchData {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Match
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to MatchData"
+    }
+}
+
+impl PartialEq for MatchData {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
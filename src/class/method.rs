@@ -0,0 +1,310 @@
+use std::convert::From;
+
+use crate::{
+    binding::method, types::Value, util, AnyObject, Array, Integer, Module, Object, Proc, RString,
+    Symbol, UnboundMethod, VerifiedObject,
+};
+
+/// `Method`
+///
+/// A method bound to its receiver, as returned by `Object::method`.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Method {
+    value: Value,
+}
+
+impl Method {
+    /// Calls the method with given arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new();
+    /// let push = array.method("push").unwrap();
+    ///
+    /// push.call(&[Fixnum::new(1).to_any_object()]);
+    ///
+    /// assert_eq!(array.length(), 1);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = []
+    /// array.method(:push).call(1)
+    ///
+    /// array.length == 1
+    /// ```
+    pub fn call(&self, arguments: &[AnyObject]) -> AnyObject {
+        let arguments = util::arguments_to_values(arguments);
+        let result = method::call(self.value(), &arguments);
+
+        AnyObject::from(result)
+    }
+
+    /// Calls the method with given arguments and a block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, Proc, VM};
+    /// # VM::init();
+    ///
+    /// let array = VM::eval("[1, 2, 3]").unwrap();
+    /// let map = array.method("map").unwrap();
+    /// let double = VM::eval("->(x) { x * 2 }").unwrap();
+    ///
+    /// let result = map.call_with_block(&[], &Proc::from(double.value()));
+    ///
+    /// assert_eq!(result, VM::eval("[2, 4, 6]").unwrap());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, 2, 3].method(:map).call { |x| x * 2 } == [2, 4, 6]
+    /// ```
+    pub fn call_with_block(&self, arguments: &[AnyObject], block: &Proc) -> AnyObject {
+        let arguments = util::arguments_to_values(arguments);
+        let result = method::call_with_block(self.value(), &arguments, block.value());
+
+        AnyObject::from(result)
+    }
+
+    /// Returns the number of mandatory arguments, or `-n - 1` for a method
+    /// with `n` mandatory arguments that also takes optional ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("hello");
+    ///
+    /// assert_eq!(string.method("upcase").unwrap().arity(), -1);
+    /// assert_eq!(string.method("start_with?").unwrap().arity(), -1);
+    /// assert_eq!(string.method("length").unwrap().arity(), 0);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// "hello".method(:length).arity == 0
+    /// ```
+    pub fn arity(&self) -> i32 {
+        let arity = unsafe { self.send("arity", &[]) };
+
+        Integer::from(arity.value()).to_i32()
+    }
+
+    /// Returns the class or module that defines the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Module, Object, VM};
+    /// # VM::init();
+    ///
+    /// let method = Array::new().method("map").unwrap();
+    ///
+    /// assert_eq!(method.owner().value(), Module::from_existing("Array").value());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [].method(:map).owner == Array
+    /// ```
+    pub fn owner(&self) -> Module {
+        let owner = unsafe { self.send("owner", &[]) };
+
+        Module::from(owner.value())
+    }
+
+    /// Returns the object the method is bound to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("hello");
+    /// let method = string.method("upcase").unwrap();
+    ///
+    /// assert!(method.receiver().equals(&string));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = "hello"
+    ///
+    /// string.method(:upcase).receiver.equal?(string) == true
+    /// ```
+    pub fn receiver(&self) -> AnyObject {
+        unsafe { self.send("receiver", &[]) }
+    }
+
+    /// Returns the name of the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Object, VM};
+    /// # VM::init();
+    ///
+    /// let method = Array::new().method("length").unwrap();
+    ///
+    /// assert_eq!(method.name().to_str(), "length");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [].method(:length).name == :length
+    /// ```
+    pub fn name(&self) -> Symbol {
+        let name = unsafe { self.send("name", &[]) };
+
+        Symbol::from(name.value())
+    }
+
+    /// Returns the file and line where the method is defined, or `None` for a
+    /// method defined in C or Rust.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Object, VM};
+    /// # VM::init();
+    ///
+    /// let method = Array::new().method("length").unwrap();
+    ///
+    /// assert_eq!(method.source_location(), None);
+    ///
+    /// let object = VM::eval("o = Object.new\ndef o.greet; end\no").unwrap();
+    /// let (_, line) = object.method("greet").unwrap().source_location().unwrap();
+    ///
+    /// assert_eq!(line, 2);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [].method(:length).source_location == nil
+    /// ```
+    pub fn source_location(&self) -> Option<(String, u32)> {
+        let location = unsafe { self.send("source_location", &[]) };
+
+        source_location(location)
+    }
+
+    /// Dissociates the method from its receiver.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let unbound = RString::new_utf8("hello").method("upcase").unwrap().unbind();
+    /// let method = unbound.bind(&RString::new_utf8("world")).unwrap();
+    ///
+    /// assert_eq!(method.call(&[]), RString::new_utf8("WORLD").to_any_object());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// "hello".method(:upcase).unbind.bind("world").call == "WORLD"
+    /// ```
+    pub fn unbind(&self) -> UnboundMethod {
+        let unbound = unsafe { self.send("unbind", &[]) };
+
+        UnboundMethod::from(unbound.value())
+    }
+
+    /// Converts the method to a lambda `Proc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Object, VM};
+    /// # VM::init();
+    ///
+    /// let to_proc = Array::new().method("push").unwrap().to_proc();
+    ///
+    /// assert!(to_proc.is_lambda());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [].method(:push).to_proc.lambda? == true
+    /// ```
+    pub fn to_proc(&self) -> Proc {
+        let to_proc = unsafe { self.send("to_proc", &[]) };
+
+        Proc::from(to_proc.value())
+    }
+}
+
+// Converts the result of `Method#source_location` to a pair of file and line.
+pub(crate) fn source_location(location: AnyObject) -> Option<(String, u32)> {
+    if location.is_nil() {
+        return None;
+    }
+
+    let location = Array::from(location.value());
+    let file = RString::from(location.at(0).value()).to_string();
+    let line = Integer::from(location.at(1).value()).to_u64() as u32;
+
+    Some((file, line))
+}
+
+impl From<Value> for Method {
+    fn from(value: Value) -> Self {
+        Method { value }
+    }
+}
+
+impl From<Method> for Value {
+    fn from(val: Method) -> Self {
+        val.value
+    }
+}
+
+impl From<Method> for AnyObject {
+    fn from(val: Method) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for Method {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for Method {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        util::is_method(object.value())
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Method"
+    }
+}
+
+impl PartialEq for Method {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
@@ -5,18 +5,41 @@ pub mod binding;
 pub mod boolean;
 #[allow(clippy::module_inception)] // we want class::class.
 pub mod class;
+pub mod complex;
+pub mod condition_variable;
 pub mod encoding;
+pub mod encoding_converter;
 pub mod enumerator;
+pub mod fiber;
+#[cfg(ruby_gte_3_1)]
+pub mod fiber_scheduler;
 pub mod fixnum;
 pub mod float;
 pub mod gc;
 pub mod hash;
 pub mod integer;
+pub mod io;
+pub mod match_data;
+pub mod method;
 pub mod module;
+pub mod mutex;
 pub mod nil_class;
+#[cfg(ruby_gte_3_3)]
+pub mod postponed_job;
+pub mod profile_frame;
+pub mod queue;
+#[cfg(ruby_gte_3_0)]
+pub mod ractor;
+pub mod range;
+pub mod rational;
+pub mod regexp;
 pub mod rproc;
+pub mod ruby_io;
 pub mod string;
 pub mod symbol;
 pub mod thread;
+pub mod time;
+pub mod tracepoint;
 pub mod traits;
+pub mod unbound_method;
 pub mod vm;
@@ -0,0 +1,265 @@
+use std::{convert::From, time::Duration};
+
+use crate::{
+    binding::mutex, types::Value, util, AnyException, AnyObject, Class, Float, NilClass, Object,
+    VerifiedObject,
+};
+
+/// `Mutex`
+///
+/// Waiting for the lock releases the GVL, so other Ruby threads keep running,
+/// and is interrupted by `Thread#raise`, `Thread#kill` and signals.
+#[derive(Debug)]
+#[repr(C)]
+pub struct RMutex {
+    value: Value,
+}
+
+impl RMutex {
+    /// Creates a new unlocked mutex
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RMutex, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = RMutex::new();
+    ///
+    /// assert!(!mutex.is_locked());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// mutex = Mutex.new
+    ///
+    /// mutex.locked? == false
+    /// ```
+    pub fn new() -> Self {
+        Self::from(mutex::new())
+    }
+
+    /// Acquires the lock, waiting until it is available
+    ///
+    /// Returns an error if the current thread already holds the lock or the
+    /// wait was interrupted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RMutex, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = RMutex::new();
+    ///
+    /// mutex.lock().unwrap();
+    /// assert!(mutex.is_locked());
+    /// assert!(mutex.lock().is_err());
+    ///
+    /// mutex.unlock().unwrap();
+    /// assert!(!mutex.is_locked());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// mutex.lock
+    /// mutex.unlock
+    /// ```
+    pub fn lock(&self) -> Result<(), AnyException> {
+        let value = self.value();
+
+        util::protect(|| AnyObject::from(mutex::lock(value))).map(|_| ())
+    }
+
+    /// Acquires the lock if it is available, returns `true` on success
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RMutex, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = RMutex::new();
+    ///
+    /// assert!(mutex.try_lock());
+    /// assert!(!mutex.try_lock());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// mutex.try_lock
+    /// ```
+    pub fn try_lock(&self) -> bool {
+        mutex::try_lock(self.value())
+    }
+
+    /// Releases the lock
+    ///
+    /// Returns an error if the current thread does not hold the lock.
+    ///
+    /// See `RMutex::lock` for an example.
+    pub fn unlock(&self) -> Result<(), AnyException> {
+        let value = self.value();
+
+        util::protect(|| AnyObject::from(mutex::unlock(value))).map(|_| ())
+    }
+
+    /// Returns `true` if any thread holds the lock
+    pub fn is_locked(&self) -> bool {
+        mutex::is_locked(self.value())
+    }
+
+    /// Returns `true` if the current thread holds the lock
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RMutex, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = RMutex::new();
+    ///
+    /// mutex.synchronize(|| assert!(mutex.is_owned())).unwrap();
+    ///
+    /// assert!(!mutex.is_owned());
+    /// ```
+    pub fn is_owned(&self) -> bool {
+        unsafe { self.send("owned?", &[]) }.value().is_true()
+    }
+
+    /// Runs `func` while holding the lock
+    ///
+    /// The lock is released even if `func` raises a Ruby exception. Returns
+    /// an error if the lock could not be acquired or `func` raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RMutex, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = RMutex::new();
+    ///
+    /// let result = mutex.synchronize(|| 1 + 2).unwrap();
+    ///
+    /// assert_eq!(result, 3);
+    /// assert!(!mutex.is_locked());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// mutex.synchronize { 1 + 2 } == 3
+    /// ```
+    pub fn synchronize<F, R>(&self, func: F) -> Result<R, AnyException>
+    where
+        F: FnOnce() -> R,
+    {
+        let value = self.value();
+        let mut func = Some(func);
+        let mut result = None;
+
+        util::protect(|| {
+            mutex::synchronize(value, || {
+                if let Some(func) = func.take() {
+                    result = Some(func());
+                }
+            });
+
+            NilClass::new().into()
+        })?;
+
+        Ok(result.expect("synchronized closure did not run"))
+    }
+
+    /// Releases the lock, sleeps for `timeout` or until woken up, and
+    /// reacquires the lock
+    ///
+    /// The current thread must hold the lock. Sleeps until woken up by
+    /// `Thread#wakeup` or `Thread#run` if `timeout` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rutie::{RMutex, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = RMutex::new();
+    ///
+    /// mutex.lock().unwrap();
+    /// mutex.sleep(Some(Duration::from_millis(1))).unwrap();
+    ///
+    /// assert!(mutex.is_owned());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// mutex.sleep(0.001)
+    /// ```
+    pub fn sleep(&self, timeout: Option<Duration>) -> Result<(), AnyException> {
+        let value = self.value();
+        let timeout = timeout_to_value(timeout);
+
+        util::protect(|| AnyObject::from(mutex::sleep(value, timeout))).map(|_| ())
+    }
+}
+
+impl Default for RMutex {
+    fn default() -> Self {
+        RMutex::new()
+    }
+}
+
+fn timeout_to_value(timeout: Option<Duration>) -> Value {
+    match timeout {
+        Some(timeout) => Float::new(timeout.as_secs_f64()).value(),
+        None => NilClass::new().value(),
+    }
+}
+
+impl From<Value> for RMutex {
+    fn from(value: Value) -> Self {
+        RMutex { value }
+    }
+}
+
+impl From<RMutex> for Value {
+    fn from(val: RMutex) -> Self {
+        val.value
+    }
+}
+
+impl From<RMutex> for AnyObject {
+    fn from(val: RMutex) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for RMutex {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for RMutex {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.class() == Class::from_existing("Mutex")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Mutex"
+    }
+}
+
+impl PartialEq for RMutex {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{binding::debug, rubysys::debug::PostponedJobHandle};
+
+/// A job registered once with `VM::preregister_postponed_job`
+///
+/// Triggering the job is async-signal-safe, the job itself runs later on a
+/// Ruby thread holding the GVL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostponedJob {
+    index: usize,
+    handle: PostponedJobHandle,
+}
+
+impl PostponedJob {
+    pub(crate) fn new(index: usize, handle: PostponedJobHandle) -> Self {
+        PostponedJob { index, handle }
+    }
+
+    /// Asks Ruby to run the job at the next safe point
+    ///
+    /// Triggering a job several times before it runs only runs it once.
+    /// This function does not allocate and can be called from a signal
+    /// handler or a thread without the GVL.
+    pub fn trigger(&self) {
+        debug::trigger_postponed_job(self.index, self.handle);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{binding::debug, types::Value, RString};
+
+/// A Ruby stack frame captured with `VM::profile_frames`
+///
+/// All values are copied into Rust strings, so a frame can be kept around
+/// after the stack it was captured from is gone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileFrame {
+    /// The label of the frame, e.g. `"block in call"`
+    pub label: Option<String>,
+    /// The label including the class path, e.g. `"Foo#bar"`
+    pub full_label: Option<String>,
+    /// The path of the file the frame belongs to
+    pub path: Option<String>,
+    /// The line currently executed in the frame
+    pub line: i32,
+    /// The name of the method the frame belongs to
+    pub method_name: Option<String>,
+    /// The path of the class or module the method is defined in
+    pub classpath: Option<String>,
+}
+
+impl ProfileFrame {
+    pub(crate) fn new(frame: Value, line: i32) -> Self {
+        ProfileFrame {
+            label: string_or_none(debug::frame_label(frame)),
+            full_label: string_or_none(debug::frame_full_label(frame)),
+            path: string_or_none(debug::frame_path(frame)),
+            line,
+            method_name: string_or_none(debug::frame_method_name(frame)),
+            classpath: string_or_none(debug::frame_classpath(frame)),
+        }
+    }
+}
+
+fn string_or_none(value: Value) -> Option<String> {
+    if value.is_nil() {
+        None
+    } else {
+        Some(RString::from(value).to_string())
+    }
+}
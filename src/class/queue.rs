@@ -0,0 +1,443 @@
+use std::{convert::From, time::Duration};
+
+use crate::{
+    types::Value, AnyException, AnyObject, Boolean, Class, Fixnum, Object, VerifiedObject,
+};
+
+/// `Queue`
+///
+/// Waiting on the queue releases the GVL and is interrupted by `Thread#raise`,
+/// `Thread#kill` and signals.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Queue {
+    value: Value,
+}
+
+impl Queue {
+    /// Creates a new empty queue
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Object, Queue, VM};
+    /// # VM::init();
+    ///
+    /// let queue = Queue::new();
+    ///
+    /// queue.push(Fixnum::new(1)).unwrap();
+    ///
+    /// assert_eq!(queue.len(), 1);
+    /// assert_eq!(queue.pop(None).unwrap().try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert!(queue.is_empty());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// queue = Queue.new
+    ///
+    /// queue.push(1)
+    /// queue.pop == 1
+    /// ```
+    pub fn new() -> Self {
+        let queue = unsafe { Class::from_existing("Queue").send("new", &[]) };
+
+        Self::from(queue.value())
+    }
+
+    /// Appends `value` to the queue, waking up a waiting thread
+    ///
+    /// Returns an error if the queue is closed.
+    ///
+    /// See `Queue::new` for an example.
+    pub fn push<T: Object>(&self, value: T) -> Result<(), AnyException> {
+        self.protect_send("push", &[value.to_any_object()])
+            .map(|_| ())
+    }
+
+    /// Removes the first value, waiting for one if the queue is empty
+    ///
+    /// Returns `nil` if `timeout` expires or the queue is closed and empty.
+    /// Timeouts require Ruby 3.2 or later, older Rubies return a
+    /// `NotImplementedError` when `timeout` is given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rutie::{Object, Queue, VM};
+    /// # VM::init();
+    ///
+    /// let queue = Queue::new();
+    ///
+    /// if let Ok(value) = queue.pop(Some(Duration::from_millis(1))) {
+    ///     assert!(value.is_nil());
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// queue.pop(timeout: 0.001)
+    /// ```
+    pub fn pop(&self, timeout: Option<Duration>) -> Result<AnyObject, AnyException> {
+        pop(self, timeout)
+    }
+
+    /// Removes the first value if there is one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Queue, RString, VM};
+    /// # VM::init();
+    ///
+    /// let queue = Queue::new();
+    ///
+    /// assert!(queue.try_pop().is_none());
+    ///
+    /// queue.push(RString::new_utf8("job")).unwrap();
+    ///
+    /// assert!(queue.try_pop().is_some());
+    /// ```
+    pub fn try_pop(&self) -> Option<AnyObject> {
+        try_pop(self)
+    }
+
+    /// Closes the queue
+    ///
+    /// Pushing to a closed queue fails, waiting threads are woken up and
+    /// receive `nil` once the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Queue, VM};
+    /// # VM::init();
+    ///
+    /// let queue = Queue::new();
+    ///
+    /// queue.close();
+    ///
+    /// assert!(queue.is_closed());
+    /// assert!(queue.push(Fixnum::new(1)).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// queue.close
+    /// queue.closed? == true
+    /// ```
+    pub fn close(&self) {
+        unsafe { self.send("close", &[]) };
+    }
+
+    /// Returns `true` if the queue is closed
+    ///
+    /// See `Queue::close` for an example.
+    pub fn is_closed(&self) -> bool {
+        unsafe { self.send("closed?", &[]) }.value().is_true()
+    }
+
+    /// Returns the number of values in the queue
+    ///
+    /// See `Queue::new` for an example.
+    pub fn len(&self) -> usize {
+        len(self)
+    }
+
+    /// Returns `true` if the queue has no values
+    ///
+    /// See `Queue::new` for an example.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of threads waiting on the queue
+    pub fn num_waiting(&self) -> usize {
+        num_waiting(self)
+    }
+}
+
+impl Default for Queue {
+    fn default() -> Self {
+        Queue::new()
+    }
+}
+
+/// `SizedQueue`
+///
+/// A `Queue` with a maximum size, pushing to a full queue waits until a value
+/// is removed.
+#[derive(Debug)]
+#[repr(C)]
+pub struct SizedQueue {
+    value: Value,
+}
+
+impl SizedQueue {
+    /// Creates a new empty queue holding at most `max` values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rutie::{Fixnum, SizedQueue, VM};
+    /// # VM::init();
+    ///
+    /// let queue = SizedQueue::new(1);
+    ///
+    /// assert_eq!(queue.max(), 1);
+    /// assert_eq!(queue.push(Fixnum::new(1), None), Ok(true));
+    ///
+    /// if let Ok(pushed) = queue.push(Fixnum::new(2), Some(Duration::from_millis(1))) {
+    ///     assert!(!pushed);
+    /// }
+    ///
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// queue = SizedQueue.new(1)
+    ///
+    /// queue.push(1)
+    /// queue.push(2, timeout: 0.001) == nil
+    /// ```
+    pub fn new(max: usize) -> Self {
+        let max = Fixnum::new(max as i64).to_any_object();
+        let queue = unsafe { Class::from_existing("SizedQueue").send("new", &[max]) };
+
+        Self::from(queue.value())
+    }
+
+    /// Appends `value` to the queue, waiting for free space if the queue is full
+    ///
+    /// Returns `false` if `timeout` expired before the value could be pushed
+    /// and an error if the queue is closed. Timeouts require Ruby 3.2 or later,
+    /// older Rubies return a `NotImplementedError` when `timeout` is given.
+    ///
+    /// See `SizedQueue::new` for an example.
+    pub fn push<T: Object>(
+        &self,
+        value: T,
+        timeout: Option<Duration>,
+    ) -> Result<bool, AnyException> {
+        let value = value.to_any_object();
+
+        let result = match timeout {
+            Some(timeout) => call_with_timeout(self, "push", Some(value.value()), timeout),
+            None => self.protect_send("push", &[value]),
+        };
+
+        result.map(|result| !result.is_nil())
+    }
+
+    /// Removes the first value, waiting for one if the queue is empty
+    ///
+    /// See `Queue::pop`.
+    pub fn pop(&self, timeout: Option<Duration>) -> Result<AnyObject, AnyException> {
+        pop(self, timeout)
+    }
+
+    /// Removes the first value if there is one
+    pub fn try_pop(&self) -> Option<AnyObject> {
+        try_pop(self)
+    }
+
+    /// Closes the queue
+    ///
+    /// See `Queue::close`.
+    pub fn close(&self) {
+        unsafe { self.send("close", &[]) };
+    }
+
+    /// Returns `true` if the queue is closed
+    pub fn is_closed(&self) -> bool {
+        unsafe { self.send("closed?", &[]) }.value().is_true()
+    }
+
+    /// Returns the number of values in the queue
+    pub fn len(&self) -> usize {
+        len(self)
+    }
+
+    /// Returns `true` if the queue has no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of values in the queue
+    pub fn max(&self) -> usize {
+        let max = unsafe { self.send("max", &[]) };
+
+        Fixnum::from(max.value()).to_i64() as usize
+    }
+
+    /// Returns the number of threads waiting on the queue
+    pub fn num_waiting(&self) -> usize {
+        num_waiting(self)
+    }
+}
+
+fn pop(queue: &impl Object, timeout: Option<Duration>) -> Result<AnyObject, AnyException> {
+    match timeout {
+        Some(timeout) => call_with_timeout(queue, "pop", None, timeout),
+        None => queue.protect_send("pop", &[]),
+    }
+}
+
+fn try_pop(queue: &impl Object) -> Option<AnyObject> {
+    let non_block = Boolean::new(true).to_any_object();
+
+    // `pop(true)` raises `ThreadError` if the queue is empty
+    queue.protect_send("pop", &[non_block]).ok()
+}
+
+fn len(queue: &impl Object) -> usize {
+    let length = unsafe { queue.send("length", &[]) };
+
+    Fixnum::from(length.value()).to_i64() as usize
+}
+
+fn num_waiting(queue: &impl Object) -> usize {
+    let waiting = unsafe { queue.send("num_waiting", &[]) };
+
+    Fixnum::from(waiting.value()).to_i64() as usize
+}
+
+// Calls `method` with an optional positional `argument` and a `timeout:` keyword argument
+#[cfg(ruby_gte_3_2)]
+fn call_with_timeout(
+    queue: &impl Object,
+    method: &'static str,
+    argument: Option<Value>,
+    timeout: Duration,
+) -> Result<AnyObject, AnyException> {
+    use crate::{binding::vm, Float, Hash, Symbol, VM};
+
+    let mut keywords = Hash::new();
+    keywords.store(Symbol::new("timeout"), Float::new(timeout.as_secs_f64()));
+
+    let mut arguments = Vec::with_capacity(2);
+
+    if let Some(argument) = argument {
+        arguments.push(argument);
+    }
+
+    arguments.push(keywords.value());
+
+    let receiver = queue.value();
+
+    VM::protect(|| AnyObject::from(vm::call_method_with_keywords(receiver, method, &arguments)))
+        .map_err(|_| {
+            let output = VM::error_info().unwrap();
+
+            // error cleanup
+            VM::clear_error_info();
+
+            output
+        })
+}
+
+// `Queue#pop` and `SizedQueue#push` only accept `timeout:` from Ruby 3.2 on
+#[cfg(not(ruby_gte_3_2))]
+fn call_with_timeout(
+    _queue: &impl Object,
+    method: &'static str,
+    _argument: Option<Value>,
+    _timeout: Duration,
+) -> Result<AnyObject, AnyException> {
+    use crate::Exception;
+
+    let message = format!("{} with a timeout requires Ruby 3.2 or later", method);
+
+    Err(AnyException::new("NotImplementedError", Some(&message)))
+}
+
+impl From<Value> for Queue {
+    fn from(value: Value) -> Self {
+        Queue { value }
+    }
+}
+
+impl From<Queue> for Value {
+    fn from(val: Queue) -> Self {
+        val.value
+    }
+}
+
+impl From<Queue> for AnyObject {
+    fn from(val: Queue) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for Queue {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for Queue {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.class() == Class::from_existing("Queue")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Queue"
+    }
+}
+
+impl PartialEq for Queue {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
+
+impl From<Value> for SizedQueue {
+    fn from(value: Value) -> Self {
+        SizedQueue { value }
+    }
+}
+
+impl From<SizedQueue> for Value {
+    fn from(val: SizedQueue) -> Self {
+        val.value
+    }
+}
+
+impl From<SizedQueue> for AnyObject {
+    fn from(val: SizedQueue) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for SizedQueue {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for SizedQueue {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.class() == Class::from_existing("SizedQueue")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to SizedQueue"
+    }
+}
+
+impl PartialEq for SizedQueue {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
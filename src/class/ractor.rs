@@ -0,0 +1,298 @@
+use std::convert::From;
+
+use crate::{
+    binding::{ractor, vm},
+    rubysys::ractor::RactorLocalKeyPtr,
+    types::Value,
+    util, AnyException, AnyObject, Class, Object, Proc, VerifiedObject,
+};
+
+/// `Ractor`
+#[derive(Debug)]
+#[repr(C)]
+pub struct Ractor {
+    value: Value,
+}
+
+impl Ractor {
+    /// Creates a new Ractor running `block` with `arguments`
+    ///
+    /// The block must be isolated, it may not access outer variables.
+    /// Returns an error otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Object, Proc, Ractor, VM};
+    /// # VM::init();
+    ///
+    /// let block = VM::eval("proc { |value| value * 2 }").unwrap();
+    /// let block = block.try_convert_to::<Proc>().unwrap();
+    ///
+    /// let ractor = Ractor::new(&[Fixnum::new(21).to_any_object()], &block).unwrap();
+    ///
+    /// let result = ractor.take().unwrap();
+    ///
+    /// assert_eq!(result.try_convert_to::<Fixnum>(), Ok(Fixnum::new(42)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// ractor = Ractor.new(21) { |value| value * 2 }
+    ///
+    /// ractor.take == 42
+    /// ```
+    pub fn new(arguments: &[AnyObject], block: &Proc) -> Result<Self, AnyException> {
+        let class = Class::from_existing("Ractor").value();
+        let arguments = util::arguments_to_values(arguments);
+        let block = block.value();
+
+        util::protect(|| {
+            AnyObject::from(vm::call_method_with_block(class, "new", &arguments, block))
+        })
+        .map(|ractor| Self::from(ractor.value()))
+    }
+
+    /// Returns the Ractor the current thread belongs to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Ractor, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Ractor::current(), Ractor::current());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Ractor.current
+    /// ```
+    pub fn current() -> Self {
+        let ractor = unsafe { Class::from_existing("Ractor").send("current", &[]) };
+
+        Self::from(ractor.value())
+    }
+
+    /// Sends `message` to the Ractor's incoming queue
+    ///
+    /// Unshareable objects are deep copied. Returns an error if the Ractor
+    /// is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Object, Proc, Ractor, VM};
+    /// # VM::init();
+    ///
+    /// let block = VM::eval("proc { Ractor.receive + 1 }").unwrap();
+    /// let block = block.try_convert_to::<Proc>().unwrap();
+    ///
+    /// let ractor = Ractor::new(&[], &block).unwrap();
+    ///
+    /// ractor.send_message(Fixnum::new(1)).unwrap();
+    ///
+    /// let result = ractor.take().unwrap();
+    ///
+    /// assert_eq!(result.try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// ractor = Ractor.new { Ractor.receive + 1 }
+    ///
+    /// ractor.send(1)
+    /// ractor.take == 2
+    /// ```
+    pub fn send_message<T: Object>(&self, message: T) -> Result<(), AnyException> {
+        self.protect_send("send", &[message.to_any_object()])
+            .map(|_| ())
+    }
+
+    /// Waits for a value yielded by the Ractor or its final result
+    ///
+    /// Returns an error if the Ractor raised an exception or is closed.
+    ///
+    /// See `Ractor::new` for an example.
+    pub fn take(&self) -> Result<AnyObject, AnyException> {
+        self.protect_send("take", &[])
+    }
+
+    /// Receives a message sent to the current Ractor, blocking until one arrives
+    ///
+    /// See `Ractor::send_message` for an example.
+    pub fn receive() -> Result<AnyObject, AnyException> {
+        Class::from_existing("Ractor").protect_send("receive", &[])
+    }
+
+    /// Deeply freezes `object` so it can be shared between Ractors
+    ///
+    /// Returns an error if `object` contains objects which can not be made
+    /// shareable, for example a `Proc` accessing outer variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Object, RString, Ractor, VM};
+    /// # VM::init();
+    ///
+    /// let mut array = Array::new();
+    /// array.push(RString::new_utf8("config"));
+    ///
+    /// assert!(!Ractor::is_shareable(&array));
+    ///
+    /// let array = Ractor::make_shareable(array).unwrap();
+    ///
+    /// assert!(Ractor::is_shareable(&array));
+    /// assert!(array.is_frozen());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = Ractor.make_shareable(["config"])
+    ///
+    /// Ractor.shareable?(array) == true
+    /// ```
+    pub fn make_shareable<T: Object>(object: T) -> Result<T, AnyException> {
+        let value = object.value();
+
+        util::protect(|| AnyObject::from(ractor::make_shareable(value))).map(|_| object)
+    }
+
+    /// Returns a shareable deep copy of `object`, leaving `object` untouched
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, Ractor, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("config");
+    ///
+    /// let copy = Ractor::make_shareable_copy(&string).unwrap();
+    ///
+    /// assert!(Ractor::is_shareable(&copy));
+    /// assert!(!string.is_frozen());
+    /// ```
+    pub fn make_shareable_copy(object: &impl Object) -> Result<AnyObject, AnyException> {
+        let value = object.value();
+
+        util::protect(|| AnyObject::from(ractor::make_shareable_copy(value)))
+    }
+
+    /// Returns `true` if `object` can be shared between Ractors
+    ///
+    /// See `Ractor::make_shareable` for an example.
+    pub fn is_shareable(object: &impl Object) -> bool {
+        let class = Class::from_existing("Ractor");
+        let result = unsafe { class.send("shareable?", &[object.to_any_object()]) };
+
+        result.value().is_true()
+    }
+}
+
+/// A key for Ractor-local storage
+///
+/// Every Ractor sees its own value for the same key, which makes it the place
+/// for per-Ractor state of an extension. Keys are meant to be created once,
+/// for example in a `static`, and are never freed.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::OnceLock;
+///
+/// use rutie::{Fixnum, Object, RactorLocalKey, VM};
+/// # VM::init();
+///
+/// static COUNTER: OnceLock<RactorLocalKey> = OnceLock::new();
+///
+/// let key = COUNTER.get_or_init(RactorLocalKey::new);
+///
+/// assert!(key.get().is_none());
+///
+/// key.set(Fixnum::new(1));
+///
+/// assert_eq!(key.get().unwrap().try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+/// ```
+#[derive(Debug)]
+pub struct RactorLocalKey {
+    key: RactorLocalKeyPtr,
+}
+
+// The key itself is an immutable process wide handle
+unsafe impl Send for RactorLocalKey {}
+unsafe impl Sync for RactorLocalKey {}
+
+impl RactorLocalKey {
+    /// Creates a new key
+    pub fn new() -> Self {
+        RactorLocalKey {
+            key: ractor::local_storage_new_key(),
+        }
+    }
+
+    /// Returns the value stored for the current Ractor
+    pub fn get(&self) -> Option<AnyObject> {
+        ractor::local_storage_get(self.key).map(AnyObject::from)
+    }
+
+    /// Stores `value` for the current Ractor
+    ///
+    /// The value is marked by the Ractor, so it is not garbage collected
+    /// while it is stored.
+    pub fn set<T: Object>(&self, value: T) {
+        ractor::local_storage_set(self.key, value.value());
+    }
+}
+
+impl Default for RactorLocalKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Value> for Ractor {
+    fn from(value: Value) -> Self {
+        Ractor { value }
+    }
+}
+
+impl From<Ractor> for Value {
+    fn from(val: Ractor) -> Self {
+        val.value
+    }
+}
+
+impl From<Ractor> for AnyObject {
+    fn from(val: Ractor) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for Ractor {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for Ractor {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.class() == Class::from_existing("Ractor")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Ractor"
+    }
+}
+
+impl PartialEq for Ractor {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
@@ -0,0 +1,423 @@
+use std::{convert::From, convert::TryFrom, ops};
+
+use crate::{
+    binding::range, types::Value, AnyException, AnyObject, Class, Exception, Integer, NilClass,
+    Object, RString, VerifiedObject, VM,
+};
+
+/// `Range`
+#[derive(Debug)]
+#[repr(C)]
+pub struct Range {
+    value: Value,
+}
+
+impl Range {
+    /// Creates a new `Range`.
+    ///
+    /// Pass a `NilClass` as `begin` or `end` to create a beginless or endless range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, NilClass, Object, Range, VM};
+    /// # VM::init();
+    ///
+    /// let range = Range::new(Integer::new(1), Integer::new(5), true);
+    ///
+    /// assert_eq!(range.begin(), Integer::new(1).to_any_object());
+    /// assert_eq!(range.end(), Integer::new(5).to_any_object());
+    /// assert!(range.is_exclusive());
+    ///
+    /// let endless = Range::new(Integer::new(1), NilClass::new(), false);
+    ///
+    /// assert!(endless.is_endless());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// range = 1...5
+    ///
+    /// range.begin == 1
+    /// range.end == 5
+    /// range.exclude_end? == true
+    ///
+    /// endless = 1..
+    ///
+    /// endless.end.nil? == true
+    /// ```
+    pub fn new<B: Object, E: Object>(begin: B, end: E, exclusive: bool) -> Self {
+        Self::from(range::new(begin.value(), end.value(), exclusive))
+    }
+
+    /// Returns the first object of the range, `nil` for a beginless range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, Range, VM};
+    /// # VM::init();
+    ///
+    /// let range = Range::from(..5);
+    ///
+    /// assert!(range.begin().is_nil());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// (...5).begin == nil
+    /// ```
+    pub fn begin(&self) -> AnyObject {
+        let (begin, _, _) = range::values(self.value());
+
+        AnyObject::from(begin)
+    }
+
+    /// Returns the object that defines the end of the range, `nil` for an endless range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, Object, Range, VM};
+    /// # VM::init();
+    ///
+    /// let range = Range::from(1..=5);
+    ///
+    /// assert_eq!(range.end(), Integer::new(5).to_any_object());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// (1..5).end == 5
+    /// ```
+    pub fn end(&self) -> AnyObject {
+        let (_, end, _) = range::values(self.value());
+
+        AnyObject::from(end)
+    }
+
+    /// Returns `true` if the range excludes its end value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Range, VM};
+    /// # VM::init();
+    ///
+    /// assert!(Range::from(1..5).is_exclusive());
+    /// assert!(!Range::from(1..=5).is_exclusive());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// (1...5).exclude_end? == true
+    /// (1..5).exclude_end? == false
+    /// ```
+    pub fn is_exclusive(&self) -> bool {
+        let (_, _, exclusive) = range::values(self.value());
+
+        exclusive
+    }
+
+    /// Returns `true` if the range has no beginning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, NilClass, Range, VM};
+    /// # VM::init();
+    ///
+    /// let range = Range::new(NilClass::new(), Integer::new(5), false);
+    ///
+    /// assert!(range.is_beginless());
+    /// assert!(!Range::from(1..5).is_beginless());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// (..5).begin.nil? == true
+    /// ```
+    pub fn is_beginless(&self) -> bool {
+        self.begin().is_nil()
+    }
+
+    /// Returns `true` if the range has no end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Range, VM};
+    /// # VM::init();
+    ///
+    /// assert!(Range::from(1..).is_endless());
+    /// assert!(!Range::from(1..5).is_endless());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// (1..).end.nil? == true
+    /// ```
+    pub fn is_endless(&self) -> bool {
+        self.end().is_nil()
+    }
+
+    /// Resolves the range against a collection of `length` elements.
+    ///
+    /// Follows Ruby's indexing rules: negative bounds count from the end and an end
+    /// past `length` is clamped. Returns `Err` with a `RangeError` when the range
+    /// starts outside of the collection, or a `TypeError` when the bounds are not
+    /// integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, NilClass, Range, VM};
+    /// # VM::init();
+    ///
+    /// let items = [10, 20, 30, 40, 50];
+    ///
+    /// let range = Range::new(Integer::new(-3), NilClass::new(), false);
+    /// let indices = range.to_indices(items.len()).unwrap();
+    ///
+    /// assert_eq!(&items[indices], &[30, 40, 50]);
+    ///
+    /// let range = Range::from(1..100);
+    ///
+    /// assert_eq!(range.to_indices(items.len()), Ok(1..5));
+    ///
+    /// let range = Range::from(6..);
+    ///
+    /// assert!(range.to_indices(items.len()).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// items = [10, 20, 30, 40, 50]
+    ///
+    /// items[-3..] == [30, 40, 50]
+    /// items[1...100] == [20, 30, 40, 50]
+    /// items[6..] == nil
+    /// ```
+    pub fn to_indices(&self, length: usize) -> Result<ops::Range<usize>, AnyException> {
+        let mut begin = 0;
+        let mut len = 0;
+
+        let result = VM::protect(|| {
+            AnyObject::from(range::beg_len(
+                self.value(),
+                &mut begin,
+                &mut len,
+                length as i64,
+            ))
+        });
+
+        match result {
+            Ok(result) if result.is_nil() => {
+                let inspect = unsafe { self.send("inspect", &[]) };
+                let message = format!("{} out of range", RString::from(inspect.value()).to_str());
+
+                Err(AnyException::new("RangeError", Some(&message)))
+            }
+            Ok(result) if result.value().is_false() => {
+                let class = unsafe { self.class().send("inspect", &[]) };
+                let message = format!(
+                    "wrong argument type {} (expected Range)",
+                    RString::from(class.value()).to_str()
+                );
+
+                Err(AnyException::new("TypeError", Some(&message)))
+            }
+            Ok(_) => Ok(begin as usize..(begin + len) as usize),
+            Err(_) => {
+                let output = VM::error_info().unwrap();
+
+                // error cleanup
+                VM::clear_error_info();
+
+                Err(output)
+            }
+        }
+    }
+
+    fn integer_bounds(&self) -> Result<(Option<i64>, Option<i64>, bool), AnyException> {
+        let (begin, end, exclusive) = range::values(self.value());
+
+        let bound = |value: Value| -> Result<Option<i64>, AnyException> {
+            if value.is_nil() {
+                return Ok(None);
+            }
+
+            AnyObject::from(value)
+                .try_convert_to::<Integer>()
+                .map(|integer| Some(integer.to_i64()))
+        };
+
+        Ok((bound(begin)?, bound(end)?, exclusive))
+    }
+}
+
+impl From<Value> for Range {
+    fn from(value: Value) -> Self {
+        Range { value }
+    }
+}
+
+impl From<Range> for Value {
+    fn from(val: Range) -> Self {
+        val.value
+    }
+}
+
+impl From<Range> for AnyObject {
+    fn from(val: Range) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl From<ops::Range<i64>> for Range {
+    fn from(range: ops::Range<i64>) -> Self {
+        Range::new(Integer::new(range.start), Integer::new(range.end), true)
+    }
+}
+
+impl From<ops::RangeInclusive<i64>> for Range {
+    fn from(range: ops::RangeInclusive<i64>) -> Self {
+        let (start, end) = range.into_inner();
+
+        Range::new(Integer::new(start), Integer::new(end), false)
+    }
+}
+
+impl From<ops::RangeFrom<i64>> for Range {
+    fn from(range: ops::RangeFrom<i64>) -> Self {
+        Range::new(Integer::new(range.start), NilClass::new(), false)
+    }
+}
+
+impl From<ops::RangeTo<i64>> for Range {
+    fn from(range: ops::RangeTo<i64>) -> Self {
+        Range::new(NilClass::new(), Integer::new(range.end), true)
+    }
+}
+
+/// Converts a bounded `Range` of integers to `std::ops::Range`.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use std::ops;
+///
+/// use rutie::{Range, VM};
+/// # VM::init();
+///
+/// assert_eq!(ops::Range::try_from(Range::from(1..=5)), Ok(1..6));
+/// assert!(ops::Range::<i64>::try_from(Range::from(1..)).is_err());
+/// ```
+impl TryFrom<Range> for ops::Range<i64> {
+    type Error = AnyException;
+
+    fn try_from(range: Range) -> Result<Self, Self::Error> {
+        match range.integer_bounds()? {
+            (Some(start), Some(end), true) => Ok(start..end),
+            (Some(start), Some(end), false) => end
+                .checked_add(1)
+                .map(|end| start..end)
+                .ok_or_else(|| AnyException::new("RangeError", Some("Range end is too big"))),
+            _ => Err(AnyException::new(
+                "RangeError",
+                Some("Range is not bounded"),
+            )),
+        }
+    }
+}
+
+/// Converts a bounded `Range` of integers to `std::ops::RangeInclusive`.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use std::ops;
+///
+/// use rutie::{Range, VM};
+/// # VM::init();
+///
+/// assert_eq!(ops::RangeInclusive::try_from(Range::from(1..5)), Ok(1..=4));
+/// assert_eq!(ops::RangeInclusive::try_from(Range::from(1..=5)), Ok(1..=5));
+/// ```
+impl TryFrom<Range> for ops::RangeInclusive<i64> {
+    type Error = AnyException;
+
+    fn try_from(range: Range) -> Result<Self, Self::Error> {
+        match range.integer_bounds()? {
+            (Some(start), Some(end), false) => Ok(start..=end),
+            (Some(start), Some(end), true) => end
+                .checked_sub(1)
+                .map(|end| start..=end)
+                .ok_or_else(|| AnyException::new("RangeError", Some("Range end is too small"))),
+            _ => Err(AnyException::new(
+                "RangeError",
+                Some("Range is not bounded"),
+            )),
+        }
+    }
+}
+
+/// Converts an endless `Range` of integers to `std::ops::RangeFrom`.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use std::ops;
+///
+/// use rutie::{Range, VM};
+/// # VM::init();
+///
+/// assert_eq!(ops::RangeFrom::try_from(Range::from(3..)), Ok(3..));
+/// assert!(ops::RangeFrom::<i64>::try_from(Range::from(3..5)).is_err());
+/// ```
+impl TryFrom<Range> for ops::RangeFrom<i64> {
+    type Error = AnyException;
+
+    fn try_from(range: Range) -> Result<Self, Self::Error> {
+        match range.integer_bounds()? {
+            (Some(start), None, _) => Ok(start..),
+            _ => Err(AnyException::new(
+                "RangeError",
+                Some("Range is not endless"),
+            )),
+        }
+    }
+}
+
+impl Object for Range {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for Range {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        Class::from_existing("Range").case_equals(object)
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Range"
+    }
+}
+
+impl PartialEq for Range {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
@@ -0,0 +1,172 @@
+use std::convert::From;
+
+use crate::{
+    binding::{float, rational},
+    types::{Value, ValueType},
+    AnyObject, Integer, Numeric, Object, VerifiedObject,
+};
+
+/// `Rational`
+#[derive(Debug)]
+#[repr(C)]
+pub struct Rational {
+    value: Value,
+}
+
+impl Rational {
+    /// Creates a new `Rational` reduced to its lowest terms.
+    ///
+    /// A zero `denominator` raises `ZeroDivisionError` in Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, Rational, VM};
+    /// # VM::init();
+    ///
+    /// let rational = Rational::new(6, 4);
+    ///
+    /// assert_eq!(rational.numerator(), Integer::new(3));
+    /// assert_eq!(rational.denominator(), Integer::new(2));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// rational = Rational(6, 4)
+    ///
+    /// rational.numerator == 3
+    /// rational.denominator == 2
+    /// ```
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        let numerator = Integer::new(numerator);
+        let denominator = Integer::new(denominator);
+
+        Self::from(rational::new(numerator.value(), denominator.value()))
+    }
+
+    /// Creates a new `Rational` from two, possibly big, `Integer`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, Rational, VM};
+    /// # VM::init();
+    ///
+    /// let rational = Rational::from_integers(&Integer::new(1), &Integer::new(3));
+    ///
+    /// assert_eq!(rational, Rational::new(1, 3));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Rational(1, 3) == 1/3r
+    /// ```
+    pub fn from_integers(numerator: &Integer, denominator: &Integer) -> Self {
+        Self::from(rational::new(numerator.value(), denominator.value()))
+    }
+
+    /// Returns the numerator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, Rational, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Rational::new(-2, 3).numerator(), Integer::new(-2));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Rational(-2, 3).numerator == -2
+    /// ```
+    pub fn numerator(&self) -> Integer {
+        Integer::from(rational::numerator(self.value()))
+    }
+
+    /// Returns the denominator, which is always positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, Rational, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Rational::new(2, -3).denominator(), Integer::new(3));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Rational(2, -3).denominator == 3
+    /// ```
+    pub fn denominator(&self) -> Integer {
+        Integer::from(rational::denominator(self.value()))
+    }
+
+    /// Retrieves the nearest `f64` value of the `Rational`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Rational, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Rational::new(1, 4).to_f64(), 0.25);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Rational(1, 4).to_f == 0.25
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        float::num_to_float(self.value())
+    }
+}
+
+impl From<Value> for Rational {
+    fn from(value: Value) -> Self {
+        Rational { value }
+    }
+}
+
+impl From<Rational> for Value {
+    fn from(val: Rational) -> Self {
+        val.value
+    }
+}
+
+impl From<Rational> for AnyObject {
+    fn from(val: Rational) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for Rational {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl Numeric for Rational {}
+
+impl VerifiedObject for Rational {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Rational
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Rational"
+    }
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
@@ -0,0 +1,323 @@
+use std::convert::From;
+
+use crate::{
+    binding::{encoding, regexp},
+    types::{Value, ValueType},
+    AnyException, AnyObject, Encoding, Integer, MatchData, Object, RString, VerifiedObject, VM,
+};
+
+/// `Regexp`
+#[derive(Debug)]
+#[repr(C)]
+pub struct Regexp {
+    value: Value,
+}
+
+impl Regexp {
+    /// Makes the match case insensitive, like `/.../i`.
+    pub const IGNORECASE: i32 = crate::rubysys::regexp::ONIG_OPTION_IGNORECASE;
+    /// Ignores whitespace and comments in the pattern, like `/.../x`.
+    pub const EXTENDED: i32 = crate::rubysys::regexp::ONIG_OPTION_EXTEND;
+    /// Makes `.` match newlines, like `/.../m`.
+    pub const MULTILINE: i32 = crate::rubysys::regexp::ONIG_OPTION_MULTILINE;
+    /// Fixes the encoding of the regexp to the encoding of its source.
+    pub const FIXEDENCODING: i32 = crate::rubysys::regexp::ARG_ENCODING_FIXED;
+    /// Matches the regexp as binary, like `/.../n`.
+    pub const NOENCODING: i32 = crate::rubysys::regexp::ARG_ENCODING_NONE;
+
+    /// Compiles a new `Regexp` from a UTF-8 pattern.
+    ///
+    /// `options` is a combination of `Regexp::IGNORECASE`, `Regexp::EXTENDED`
+    /// and `Regexp::MULTILINE`. Returns `Err` with a `RegexpError` when the
+    /// pattern is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("h(.)llo", Regexp::IGNORECASE).unwrap();
+    ///
+    /// assert!(regexp.is_match(&RString::new_utf8("HELLO")));
+    /// assert!(Regexp::new("(", 0).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// regexp = Regexp.new("h(.)llo", Regexp::IGNORECASE)
+    ///
+    /// regexp.match?("HELLO") == true
+    /// ```
+    pub fn new(pattern: &str, options: i32) -> Result<Self, AnyException> {
+        Self::from_rstring(&RString::new_utf8(pattern), options)
+    }
+
+    /// Compiles a new `Regexp` from a pattern in any encoding.
+    ///
+    /// The regexp takes the encoding of `pattern`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Encoding, EncodingSupport, Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let pattern = RString::from_bytes(b"\xff+", &Encoding::find("ASCII-8BIT").unwrap());
+    /// let regexp = Regexp::from_rstring(&pattern, 0).unwrap();
+    ///
+    /// assert_eq!(regexp.encoding().name(), "ASCII-8BIT");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Regexp.new("\xff+".b).encoding == Encoding::ASCII_8BIT
+    /// ```
+    pub fn from_rstring(pattern: &RString, options: i32) -> Result<Self, AnyException> {
+        let result = VM::protect(|| AnyObject::from(regexp::new(pattern.value(), options)));
+
+        match result {
+            Ok(regexp) => Ok(Self::from(regexp.value())),
+            Err(_) => {
+                let output = VM::error_info().unwrap();
+
+                // error cleanup
+                VM::clear_error_info();
+
+                Err(output)
+            }
+        }
+    }
+
+    /// Escapes all characters with a special meaning in a regexp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Regexp::escape("1.5 * 2").to_str(), "1\\.5\\ \\*\\ 2");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Regexp.escape("1.5 * 2") == "1\\.5\\ \\*\\ 2"
+    /// ```
+    pub fn escape(string: &str) -> RString {
+        RString::from(regexp::quote(RString::new_utf8(string).value()))
+    }
+
+    /// Returns the source of the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("a+b", Regexp::MULTILINE).unwrap();
+    ///
+    /// assert_eq!(regexp.source().to_str(), "a+b");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /a+b/m.source == "a+b"
+    /// ```
+    pub fn source(&self) -> RString {
+        let source = unsafe { self.send("source", &[]) };
+
+        RString::from(source.value())
+    }
+
+    /// Returns the options the regexp was compiled with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("a", Regexp::IGNORECASE | Regexp::EXTENDED).unwrap();
+    ///
+    /// assert_eq!(regexp.options() & Regexp::IGNORECASE, Regexp::IGNORECASE);
+    /// assert_eq!(regexp.options() & Regexp::MULTILINE, 0);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /a/ix.options == Regexp::IGNORECASE | Regexp::EXTENDED
+    /// ```
+    pub fn options(&self) -> i32 {
+        regexp::options(self.value())
+    }
+
+    /// Returns the encoding the regexp matches in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("é", 0).unwrap();
+    ///
+    /// assert_eq!(regexp.encoding().name(), "UTF-8");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /é/.encoding == Encoding::UTF_8
+    /// ```
+    pub fn encoding(&self) -> Encoding {
+        Encoding::from(encoding::from_encoding_index(encoding::enc_get_index(
+            self.value(),
+        )))
+    }
+
+    /// Returns `true` if the regexp matches anywhere in `string`.
+    ///
+    /// Unlike `match_at` it does not allocate a `MatchData` or set `$~`. Returns
+    /// `false` when `string` can not be matched, see `match_at`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("\\d+", 0).unwrap();
+    ///
+    /// assert!(regexp.is_match(&RString::new_utf8("abc 123")));
+    /// assert!(!regexp.is_match(&RString::new_utf8("abc")));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /\d+/.match?("abc 123") == true
+    /// ```
+    pub fn is_match(&self, string: &RString) -> bool {
+        self.protect_send("match?", &[string.to_any_object()])
+            .map(|result| result.value().is_true())
+            .unwrap_or(false)
+    }
+
+    /// Matches the regexp against `string`, starting at the character
+    /// position `pos`.
+    ///
+    /// A negative `pos` counts from the end of the string. Returns `None` when
+    /// there is no match, and also when `string` can not be matched because its
+    /// encoding is invalid or not compatible with the encoding of the regexp
+    /// (see `EncodingSupport`). Use `try_match_at` to get that error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("o.", 0).unwrap();
+    /// let string = RString::new_utf8("foo bar of");
+    ///
+    /// let match_data = regexp.match_at(&string, 3).unwrap();
+    ///
+    /// assert_eq!(match_data.capture_str(0), Some("of"));
+    /// assert!(regexp.match_at(&string, 9).is_none());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /o./.match("foo bar of", 3)[0] == "of"
+    /// /o./.match("foo bar of", 9) == nil
+    /// ```
+    pub fn match_at(&self, string: &RString, pos: i64) -> Option<MatchData> {
+        self.try_match_at(string, pos).ok().flatten()
+    }
+
+    /// Matches the regexp against `string` like `match_at`, but returns `Err`
+    /// when Ruby refuses to match the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Encoding, Regexp, RString, VM};
+    /// # VM::init();
+    ///
+    /// let regexp = Regexp::new("é", 0).unwrap();
+    /// let latin1 = RString::from_bytes(b"\xe9", &Encoding::find("ISO-8859-1").unwrap());
+    ///
+    /// assert!(regexp.try_match_at(&latin1, 0).is_err());
+    /// assert!(regexp.try_match_at(&RString::new_utf8("e"), 0).unwrap().is_none());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// /é/.match("\xe9".force_encoding("ISO-8859-1")) # => Encoding::CompatibilityError
+    /// ```
+    pub fn try_match_at(
+        &self,
+        string: &RString,
+        pos: i64,
+    ) -> Result<Option<MatchData>, AnyException> {
+        let arguments = [string.to_any_object(), Integer::new(pos).to_any_object()];
+
+        self.protect_send("match", &arguments).map(|result| {
+            if result.is_nil() {
+                None
+            } else {
+                Some(MatchData::from(result.value()))
+            }
+        })
+    }
+}
+
+impl From<Value> for Regexp {
+    fn from(value: Value) -> Self {
+        Regexp { value }
+    }
+}
+
+impl From<Regexp> for Value {
+    fn from(val: Regexp) -> Self {
+        val.value
+    }
+}
+
+impl From<Regexp> for AnyObject {
+    fn from(val: Regexp) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for Regexp {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for Regexp {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Regexp
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Regexp"
+    }
+}
+
+impl PartialEq for Regexp {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
@@ -0,0 +1,568 @@
+use std::{
+    convert::From,
+    io::{self, BufRead, BufReader, Read, Write},
+    sync::Once,
+};
+
+use crate::{
+    binding::string,
+    methods,
+    types::{Argc, Value},
+    util, wrappable_struct, AnyException, AnyObject, Boolean, Class, Encoding, Exception, Integer,
+    Module, NilClass, Object, RString, Symbol, Thread, VerifiedObject, VM,
+};
+
+/// A Rust stream to expose to Ruby with `RubyIO::wrap`.
+pub enum IOStream {
+    /// A stream Ruby can read from with `read`, `readpartial`, `gets` and `each_line`.
+    Reader(Box<dyn Read>),
+    /// A stream Ruby can write to with `write` and `flush`.
+    Writer(Box<dyn Write>),
+}
+
+impl IOStream {
+    /// Wraps any `Read` implementor.
+    pub fn reader<R: Read + 'static>(reader: R) -> Self {
+        IOStream::Reader(Box::new(reader))
+    }
+
+    /// Wraps any `Write` implementor.
+    pub fn writer<W: Write + 'static>(writer: W) -> Self {
+        IOStream::Writer(Box::new(writer))
+    }
+}
+
+/// The Rust stream behind a `RubyIO` object.
+///
+/// While a blocking call runs without the GVL, its reader or writer is moved out
+/// of the struct and `busy` is set, so other Ruby threads never reach the stream.
+pub struct StreamData {
+    reader: Option<BufReader<Box<dyn Read>>>,
+    writer: Option<Box<dyn Write>>,
+    closed: bool,
+    busy: bool,
+}
+
+wrappable_struct!(StreamData, StreamDataWrapper, STREAM_DATA_WRAPPER);
+
+impl StreamData {
+    fn check_ready(&self) -> Result<(), AnyException> {
+        if self.closed {
+            Err(AnyException::new("IOError", Some("closed stream")))
+        } else if self.busy {
+            Err(AnyException::new(
+                "IOError",
+                Some("stream is in use by another thread"),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Runs `func` on the reader or writer returned by `stream` with the GVL released.
+    // The stream is taken out of `self` until `func` returns, so nothing else holds a
+    // reference to it while other Ruby threads run.
+    fn without_gvl<S, F, R>(
+        &mut self,
+        stream: fn(&mut Self) -> &mut Option<S>,
+        mut func: F,
+    ) -> Result<R, AnyException>
+    where
+        F: FnMut(&mut S) -> io::Result<R>,
+    {
+        self.check_ready()?;
+
+        let mut taken = match stream(self).take() {
+            Some(taken) => taken,
+            None => return Err(AnyException::new("IOError", Some("closed stream"))),
+        };
+
+        self.busy = true;
+
+        let result = Thread::call_without_gvl(|| func(&mut taken), None::<fn()>);
+
+        *stream(self) = Some(taken);
+        self.busy = false;
+
+        result.map_err(|error| AnyException::new("IOError", Some(&error.to_string())))
+    }
+
+    // Returns `true` when the reader is at the end of the stream.
+    fn fill(&mut self) -> Result<bool, AnyException> {
+        self.check_ready()?;
+
+        let reader = match self.reader {
+            Some(ref reader) => reader,
+            None => return Err(AnyException::new("IOError", Some("not opened for reading"))),
+        };
+
+        if !reader.buffer().is_empty() {
+            return Ok(false);
+        }
+
+        self.without_gvl(
+            |data| &mut data.reader,
+            |reader| reader.fill_buf().map(|buffer| buffer.is_empty()),
+        )
+    }
+
+    fn buffer(&self) -> &[u8] {
+        self.reader
+            .as_ref()
+            .map(|reader| reader.buffer())
+            .unwrap_or(&[])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        if let Some(ref mut reader) = self.reader {
+            reader.consume(amount);
+        }
+    }
+
+    fn read_up_to(&mut self, length: usize, partial: bool) -> Result<Vec<u8>, AnyException> {
+        let mut bytes = Vec::new();
+
+        while bytes.len() < length && !self.fill()? {
+            let available = self.buffer();
+            let amount = available.len().min(length - bytes.len());
+
+            bytes.extend_from_slice(&available[..amount]);
+            self.consume(amount);
+
+            if partial {
+                break;
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    fn read_line(&mut self, separator: Option<&[u8]>) -> Result<Option<Vec<u8>>, AnyException> {
+        let mut line = Vec::new();
+
+        while !self.fill()? {
+            let available = self.buffer();
+            let start = line.len();
+            let mut found = false;
+
+            match separator {
+                Some(separator) => {
+                    for byte in available {
+                        line.push(*byte);
+
+                        if line.ends_with(separator) {
+                            found = true;
+                            break;
+                        }
+                    }
+                }
+                None => line.extend_from_slice(available),
+            }
+
+            let amount = line.len() - start;
+            self.consume(amount);
+
+            if found {
+                break;
+            }
+        }
+
+        if line.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(line))
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), AnyException> {
+        if self.writer.is_none() {
+            self.check_ready()?;
+
+            return Err(AnyException::new("IOError", Some("not opened for writing")));
+        }
+
+        self.without_gvl(|data| &mut data.writer, |writer| writer.write_all(bytes))
+    }
+
+    fn flush(&mut self) -> Result<(), AnyException> {
+        if self.writer.is_none() {
+            return self.check_ready();
+        }
+
+        self.without_gvl(|data| &mut data.writer, |writer| writer.flush())
+    }
+}
+
+/// A Ruby object implementing the `IO` duck type on top of a Rust `Read` or `Write`
+/// implementor.
+///
+/// Instances are of class `Rutie::IO`, which includes `Enumerable` over lines and
+/// works with `IO.copy_stream`. Readers support `read`, `readpartial`, `gets`,
+/// `each_line` (aliased as `each`) and `eof?`; writers support `write`, `<<`, `print`,
+/// `puts` and `flush`; both support `close` and `closed?`.
+///
+/// Blocking reads, writes and flushes run with the GVL released, so other Ruby
+/// threads keep running. They are not interruptible, so `Thread#kill` waits for them.
+/// A stream can be used by one Ruby thread at a time; other threads get an `IOError`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+///
+/// use rutie::{IOStream, Object, RubyIO, VM};
+/// # VM::init();
+///
+/// let io = RubyIO::wrap(IOStream::reader(Cursor::new("one\ntwo\n")));
+///
+/// let chomp_lines = VM::eval("->(io) { io.each_line.map(&:chomp) }").unwrap();
+/// let lines = unsafe { chomp_lines.send("call", &[io.to_any_object()]) };
+///
+/// assert_eq!(lines, VM::eval("['one', 'two']").unwrap());
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// io.each_line.map(&:chomp) == ["one", "two"]
+/// ```
+#[derive(Debug)]
+#[repr(C)]
+pub struct RubyIO {
+    value: Value,
+}
+
+impl RubyIO {
+    /// Wraps a Rust stream in a new `Rutie::IO` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    ///
+    /// use rutie::{IOStream, Object, RString, RubyIO, VM};
+    /// # VM::init();
+    ///
+    /// let reader = RubyIO::wrap(IOStream::reader(Cursor::new(b"hello world".to_vec())));
+    ///
+    /// let writer = VM::eval("require 'stringio'; StringIO.new").unwrap();
+    /// let copy_stream = VM::eval("IO.method(:copy_stream)").unwrap();
+    ///
+    /// unsafe { copy_stream.send("call", &[reader.to_any_object(), writer.clone()]) };
+    ///
+    /// let contents = unsafe { writer.send("string", &[]) };
+    ///
+    /// assert_eq!(RString::from(contents.value()).to_str(), "hello world");
+    /// ```
+    pub fn wrap(stream: IOStream) -> Self {
+        let data = match stream {
+            IOStream::Reader(reader) => StreamData {
+                reader: Some(BufReader::new(reader)),
+                writer: None,
+                closed: false,
+                busy: false,
+            },
+            IOStream::Writer(writer) => StreamData {
+                reader: None,
+                writer: Some(writer),
+                closed: false,
+                busy: false,
+            },
+        };
+
+        Self::class().wrap_data(data, &*STREAM_DATA_WRAPPER)
+    }
+
+    /// Returns the `Rutie::IO` class, defining it on first use.
+    pub fn class() -> Class {
+        static DEFINE: Once = Once::new();
+
+        DEFINE.call_once(|| {
+            Module::new("Rutie")
+                .define_nested_class("IO", None)
+                .define(|klass| {
+                    klass.include("Enumerable");
+
+                    klass.def("read", read);
+                    klass.def("readpartial", readpartial);
+                    klass.def("gets", gets);
+                    klass.def("each_line", each_line);
+                    klass.def("each", each_line);
+                    klass.def("eof?", is_eof);
+                    klass.def("eof", is_eof);
+                    klass.def("write", write);
+                    klass.def("print", print);
+                    klass.def("<<", append);
+                    klass.def("puts", puts);
+                    klass.def("flush", flush);
+                    klass.def("close", close);
+                    klass.def("closed?", is_closed);
+                });
+        });
+
+        Module::from_existing("Rutie").get_nested_class("IO")
+    }
+
+    // Other Ruby threads may borrow the same data while the GVL is released, which is
+    // why `StreamData::without_gvl` never keeps the stream in it meanwhile.
+    fn data(&mut self) -> &mut StreamData {
+        self.get_data_mut(&*STREAM_DATA_WRAPPER)
+    }
+}
+
+fn unwrap_or_raise<T>(result: Result<T, AnyException>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(exception) => {
+            VM::raise_ex(exception);
+            unreachable!()
+        }
+    }
+}
+
+fn to_rstring(bytes: &[u8], binary: bool) -> RString {
+    if binary {
+        RString::from(string::new_binary(bytes))
+    } else {
+        RString::from_bytes(bytes, &Encoding::default_external())
+    }
+}
+
+// Fills `outbuf` like `IO#read(length, outbuf)` when it is given.
+fn into_outbuf(string: RString, outbuf: Result<RString, AnyException>) -> AnyObject {
+    match outbuf {
+        Ok(outbuf) => unsafe { outbuf.send("replace", &[string.to_any_object()]) },
+        Err(_) => string.to_any_object(),
+    }
+}
+
+fn separator(arguments: &[AnyObject]) -> Result<Option<Vec<u8>>, AnyException> {
+    match arguments.first() {
+        None => Ok(Some(b"\n".to_vec())),
+        Some(separator) if separator.is_nil() => Ok(None),
+        Some(separator) => {
+            let separator = separator.try_convert_to::<RString>()?;
+            let bytes = separator.to_bytes_unchecked().to_vec();
+
+            if bytes.is_empty() {
+                Err(AnyException::new(
+                    "ArgumentError",
+                    Some("paragraph mode is not supported"),
+                ))
+            } else {
+                Ok(Some(bytes))
+            }
+        }
+    }
+}
+
+methods!(
+    RubyIO,
+    rtself,
+    fn read(length: Integer, outbuf: RString) -> AnyObject {
+        let data = rtself.data();
+
+        let result = match length {
+            Ok(length) => {
+                let length = length.to_i64();
+
+                if length < 0 {
+                    Err(AnyException::new(
+                        "ArgumentError",
+                        Some("negative length given"),
+                    ))
+                } else {
+                    data.read_up_to(length as usize, false).map(|bytes| {
+                        if bytes.is_empty() && length > 0 {
+                            NilClass::new().to_any_object()
+                        } else {
+                            into_outbuf(to_rstring(&bytes, true), outbuf)
+                        }
+                    })
+                }
+            }
+            Err(_) => data
+                .read_up_to(usize::MAX, false)
+                .map(|bytes| into_outbuf(to_rstring(&bytes, false), outbuf)),
+        };
+
+        unwrap_or_raise(result)
+    },
+    fn readpartial(length: Integer, outbuf: RString) -> RString {
+        let data = rtself.data();
+
+        let result = length.and_then(|length| {
+            let length = length.to_i64();
+
+            if length < 0 {
+                return Err(AnyException::new(
+                    "ArgumentError",
+                    Some("negative length given"),
+                ));
+            }
+
+            let bytes = data.read_up_to(length as usize, true)?;
+
+            if bytes.is_empty() && length > 0 {
+                Err(AnyException::new("EOFError", Some("end of file reached")))
+            } else {
+                Ok(into_outbuf(to_rstring(&bytes, true), outbuf))
+            }
+        });
+
+        RString::from(unwrap_or_raise(result).value())
+    },
+    fn is_eof() -> Boolean {
+        let result = rtself.data().fill();
+
+        Boolean::new(unwrap_or_raise(result))
+    },
+    fn flush() -> RubyIO {
+        unwrap_or_raise(rtself.data().flush());
+
+        rtself
+    },
+    fn close() -> NilClass {
+        let data = rtself.data();
+
+        if data.closed {
+            return NilClass::new();
+        }
+
+        unwrap_or_raise(data.flush());
+
+        data.reader = None;
+        data.writer = None;
+        data.closed = true;
+
+        NilClass::new()
+    },
+    fn is_closed() -> Boolean {
+        Boolean::new(rtself.data().closed)
+    },
+    fn append(object: AnyObject) -> RubyIO {
+        let string = unsafe { unwrap_or_raise(object).send("to_s", &[]) };
+        let string = RString::from(string.value());
+
+        unwrap_or_raise(rtself.data().write(string.to_bytes_unchecked()));
+
+        rtself
+    }
+);
+
+pub extern "C" fn gets(argc: Argc, argv: *const AnyObject, mut rtself: RubyIO) -> AnyObject {
+    let arguments = unsafe { util::parse_arguments(argc, argv) };
+
+    let result =
+        separator(&arguments).and_then(|separator| rtself.data().read_line(separator.as_deref()));
+
+    match unwrap_or_raise(result) {
+        Some(line) => to_rstring(&line, false).to_any_object(),
+        None => NilClass::new().to_any_object(),
+    }
+}
+
+pub extern "C" fn each_line(argc: Argc, argv: *const AnyObject, mut rtself: RubyIO) -> AnyObject {
+    let arguments = unsafe { util::parse_arguments(argc, argv) };
+
+    if !VM::is_block_given() {
+        let mut enum_arguments = vec![Symbol::new("each_line").to_any_object()];
+        enum_arguments.extend(arguments);
+
+        return unsafe { rtself.send("enum_for", &enum_arguments) };
+    }
+
+    let separator = unwrap_or_raise(separator(&arguments));
+
+    while let Some(line) = unwrap_or_raise(rtself.data().read_line(separator.as_deref())) {
+        VM::yield_object(to_rstring(&line, false));
+    }
+
+    rtself.to_any_object()
+}
+
+pub extern "C" fn write(argc: Argc, argv: *const AnyObject, mut rtself: RubyIO) -> Integer {
+    let arguments = unsafe { util::parse_arguments(argc, argv) };
+    let mut written = 0;
+
+    for argument in arguments {
+        let string = unsafe { argument.send("to_s", &[]) };
+        let string = RString::from(string.value());
+        let bytes = string.to_bytes_unchecked();
+
+        unwrap_or_raise(rtself.data().write(bytes));
+
+        written += bytes.len() as u64;
+    }
+
+    Integer::from(written)
+}
+
+pub extern "C" fn print(argc: Argc, argv: *const AnyObject, rtself: RubyIO) -> NilClass {
+    write(argc, argv, rtself);
+
+    NilClass::new()
+}
+
+pub extern "C" fn puts(argc: Argc, argv: *const AnyObject, mut rtself: RubyIO) -> NilClass {
+    let arguments = unsafe { util::parse_arguments(argc, argv) };
+
+    if arguments.is_empty() {
+        unwrap_or_raise(rtself.data().write(b"\n"));
+    }
+
+    for argument in arguments {
+        let string = unsafe { argument.send("to_s", &[]) };
+        let string = RString::from(string.value());
+        let bytes = string.to_bytes_unchecked();
+
+        unwrap_or_raise(rtself.data().write(bytes));
+
+        if !bytes.ends_with(b"\n") {
+            unwrap_or_raise(rtself.data().write(b"\n"));
+        }
+    }
+
+    NilClass::new()
+}
+
+impl From<Value> for RubyIO {
+    fn from(value: Value) -> Self {
+        RubyIO { value }
+    }
+}
+
+impl From<RubyIO> for Value {
+    fn from(val: RubyIO) -> Self {
+        val.value
+    }
+}
+
+impl From<RubyIO> for AnyObject {
+    fn from(val: RubyIO) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for RubyIO {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for RubyIO {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        RubyIO::class().case_equals(object)
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to RubyIO"
+    }
+}
+
+impl PartialEq for RubyIO {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
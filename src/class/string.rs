@@ -1,10 +1,23 @@
-use std::convert::From;
+use std::{
+    cmp::Ordering,
+    convert::{From, TryFrom},
+    ffi::{OsStr, OsString},
+    fmt, io,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+    path::{Path, PathBuf},
+    slice,
+};
+
+#[cfg(unix)]
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
 
 use crate::{
     binding::{encoding, string},
     types::{Value, ValueType},
-    AnyException, AnyObject, Array, Boolean, CodepointIterator, Encoding, EncodingSupport,
-    Exception, Hash, Integer, NilClass, Object, TryConvert, VerifiedObject,
+    AnyException, AnyObject, Array, ArrayIterator, Boolean, CodepointIterator, Encoding,
+    EncodingSupport, Exception, Hash, Integer, NilClass, Object, Symbol, TryConvert,
+    VerifiedObject, VM,
 };
 
 /// `String`
@@ -92,6 +105,102 @@ impl RString {
         Self::from(string::new(string))
     }
 
+    /// Creates a new frozen UTF-8 `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_frozen("Hello");
+    ///
+    /// assert!(string.is_frozen());
+    /// assert_eq!(string.to_str(), "Hello");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// "Hello".freeze
+    /// ```
+    pub fn new_frozen(string: &str) -> Self {
+        Self::from(string::new_frozen(string::new_utf8(string)))
+    }
+
+    /// Returns the deduplicated frozen UTF-8 `String` with the given content.
+    ///
+    /// Equal strings share a single object from Ruby's table of frozen strings, which is
+    /// also used for `# frozen_string_literal: true` literals. Only strings that are still
+    /// referenced stay in the table, see the `rstr!` macro to keep one around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let first = RString::interned("ok");
+    /// let second = RString::interned("ok");
+    ///
+    /// assert!(first.is_frozen());
+    /// assert_eq!(first.value(), second.value());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// -"ok"
+    /// ```
+    pub fn interned(string: &str) -> Self {
+        Self::from(string::interned(string))
+    }
+
+    /// Returns the deduplicated frozen `String` equal to this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("ok");
+    ///
+    /// assert_eq!(string.to_interned().value(), RString::interned("ok").value());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// -"ok".dup
+    /// ```
+    pub fn to_interned(&self) -> Self {
+        Self::from(string::to_interned(self.value()))
+    }
+
+    /// Creates a new empty UTF-8 `String` with room for at least `capacity` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::with_capacity(1024);
+    ///
+    /// assert_eq!(string.bytesize(), 0);
+    /// assert!(string.capacity() >= 1024);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// String.new(capacity: 1024, encoding: "UTF-8")
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from(string::with_capacity(capacity))
+    }
+
     /// Creates a new instance of Ruby `String` from given byte
     /// sequence with given `Encoding`.
     ///
@@ -120,6 +229,153 @@ impl RString {
         Self::from(string::new_from_bytes(bytes, enc.value()))
     }
 
+    /// Creates a new `String` from an `OsStr`, tagged with the filesystem encoding.
+    ///
+    /// On Unix the bytes are kept as they are, even when they are not valid in the
+    /// filesystem encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use rutie::{Encoding, EncodingSupport, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::from_os_str(OsStr::new("file.txt"));
+    ///
+    /// assert_eq!(string.to_str(), "file.txt");
+    /// assert_eq!(string.encoding(), Encoding::filesystem());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// "file.txt".dup.force_encoding(Encoding.find("filesystem"))
+    /// ```
+    pub fn from_os_str(os_str: &OsStr) -> Self {
+        let filesystem = encoding::filesystem_encoding();
+
+        #[cfg(unix)]
+        let string = string::new_from_bytes(os_str.as_bytes(), filesystem);
+
+        #[cfg(not(unix))]
+        let string = string::new_from_bytes(os_str.to_string_lossy().as_bytes(), filesystem);
+
+        Self::from(string)
+    }
+
+    /// Creates a new `String` from a `Path`, tagged with the filesystem encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let path = Path::new("/tmp/file.txt");
+    /// let string = RString::from_path(path);
+    ///
+    /// assert_eq!(string.to_str(), "/tmp/file.txt");
+    /// assert_eq!(string.to_path_buf().unwrap(), path);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Pathname.new("/tmp/file.txt").to_path
+    /// ```
+    pub fn from_path(path: &Path) -> Self {
+        Self::from_os_str(path.as_os_str())
+    }
+
+    /// Converts the string to an `OsString`.
+    ///
+    /// Strings in the filesystem encoding, binary strings and ASCII only strings are
+    /// converted byte for byte, others are transcoded to the filesystem encoding first.
+    /// Returns `Err` with an `EncodingError` when the string cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// use rutie::{Encoding, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("file.txt");
+    ///
+    /// assert_eq!(string.to_os_string(), Ok(OsString::from("file.txt")));
+    ///
+    /// // A lone UTF-16 surrogate has no equivalent in any other encoding
+    /// let utf16 = Encoding::find("UTF-16LE").unwrap();
+    /// let invalid = RString::from_bytes(&[0x00, 0xd8], &utf16);
+    ///
+    /// assert!(invalid.to_os_string().is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// "file.txt".encode(Encoding.find("filesystem"))
+    ///
+    /// "\x00\xD8".force_encoding("UTF-16LE").encode(Encoding.find("filesystem")) # raises
+    /// ```
+    pub fn to_os_string(&self) -> Result<OsString, AnyException> {
+        let value = self.value();
+        let filesystem = encoding::filesystem_encoding();
+        let index = encoding::enc_get_index(value);
+
+        let is_raw = string::is_ascii_only(value)
+            || index == encoding::enc_get_index(filesystem)
+            || index == encoding::find_encoding_index("ASCII-8BIT");
+
+        let bytes = if is_raw {
+            self.to_vec_u8_unchecked()
+        } else {
+            // Unlike `rb_str_export_to_enc`, `String#encode` raises if conversion fails
+            let result = VM::protect(|| {
+                let nil = NilClass::new().value();
+
+                AnyObject::from(encoding::encode(value, filesystem, 0, nil))
+            });
+
+            match result {
+                Ok(converted) => RString::from(converted.value()).to_vec_u8_unchecked(),
+                Err(_) => {
+                    let output = VM::error_info().unwrap();
+
+                    // error cleanup
+                    VM::clear_error_info();
+
+                    return Err(output);
+                }
+            }
+        };
+
+        bytes_to_os_string(bytes)
+    }
+
+    /// Converts the string to a `PathBuf`, see `to_os_string`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("/tmp/file.txt");
+    ///
+    /// assert_eq!(string.to_path_buf(), Ok(PathBuf::from("/tmp/file.txt")));
+    /// ```
+    pub fn to_path_buf(&self) -> Result<PathBuf, AnyException> {
+        self.to_os_string().map(PathBuf::from)
+    }
+
     /// Retrieves underlying Rust `String` from Ruby `String` object.
     ///
     /// # Examples
@@ -149,7 +405,400 @@ impl RString {
     ///
     /// Unlike `to_string()` it does not perform any checks for internal null-bytes.
     ///
-    /// This function may be used to safely get binary data from Ruby.
+    /// This function may be used to safely get binary data from Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Hello,\0World!");
+    ///
+    /// assert_eq!(string.to_string_unchecked(), "Hello,\0World!".to_string());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// str = 'Hello,\0World!'
+    ///
+    /// str == 'Hello,\0World!'
+    /// ```
+    pub fn to_string_unchecked(&self) -> String {
+        string::value_to_string_unchecked(self.value())
+    }
+
+    /// Retrieves `Vec<u8>` from Ruby `String` object.
+    ///
+    /// Unlike `to_string()` it does not perform any checks for internal null-bytes.
+    ///
+    /// This function may be used to safely get binary data from Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Hello,\0World!");
+    ///
+    /// assert_eq!(string.to_vec_u8_unchecked(), (b"Hello,\0World!").to_vec());
+    /// ```
+    pub fn to_vec_u8_unchecked(&self) -> Vec<u8> {
+        self.to_bytes_unchecked().to_vec()
+    }
+
+    /// Retrieves underlying `&str` from Ruby `String` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Hello, World!");
+    ///
+    /// assert_eq!(string.to_str(), "Hello, World!");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// str = 'Hello, World!'
+    ///
+    /// str == 'Hello, World!'
+    /// ```
+    pub fn to_str(&self) -> &str {
+        let value = self.value();
+
+        string::value_to_str(value)
+    }
+
+    /// Retrieves underlying `&str` from Ruby `String` object.
+    ///
+    /// Unlike `to_str()` it does not perform any checks for internal null-bytes.
+    ///
+    /// This function may be used to safely get binary data from Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Hello,\0World!");
+    ///
+    /// assert_eq!(string.to_str_unchecked(), "Hello,\0World!");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// str = 'Hello,\0World!'
+    ///
+    /// str == 'Hello,\0World!'
+    /// ```
+    pub fn to_str_unchecked(&self) -> &str {
+        let value = self.value();
+
+        string::value_to_str_unchecked(value)
+    }
+
+    /// Retrieves underlying `&[u8]` from Ruby `String` object.
+    ///
+    /// Unlike `to_str()` it does not perform any checks for internal null-bytes.
+    ///
+    /// This function may be used to safely get binary data from Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Hello,\0World!");
+    ///
+    /// assert_eq!(string.to_bytes_unchecked(), b"Hello,\0World!");
+    /// ```
+    pub fn to_bytes_unchecked(&self) -> &[u8] {
+        let value = self.value();
+
+        string::value_to_bytes_unchecked(value)
+    }
+
+    /// Returns an array of each characters codepoints.  This is useful as
+    /// a strings encoding determines where the codepoints are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, Array, Fixnum, Encoding, EncodingSupport, VM};
+    /// # VM::init();
+    /// # VM::init_loadpath(); // Needed for alternate encodings
+    /// VM::require("enc/encdb");
+    /// VM::require("enc/trans/transdb");
+    ///
+    /// let string = RString::from_bytes(b"foo\x93_a", &Encoding::find("cp932").unwrap());
+    ///
+    /// let codepoints: Array = [102, 111, 111, 37727, 97].
+    ///   into_iter().map(|cp| Fixnum::new(cp as i64).to_any_object()).collect();
+    ///
+    /// let codepoints2: Array = [0, 0, 0, 0, 0].
+    ///   into_iter().map(|cp| Fixnum::new(cp as i64).to_any_object()).collect();
+    ///
+    /// assert_eq!(string.codepoints(), codepoints);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// str = "foo\x93_a".force_encoding("cp932")
+    ///
+    /// str.codepoints == [102, 111, 111, 37727, 97]
+    /// ```
+    pub fn codepoints(&self) -> Array {
+        CodepointIterator::new(self)
+            .map(|n| Integer::new(n as i64).to_any_object())
+            .collect()
+    }
+
+    /// Returns the length of the string in bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Hello, World!");
+    /// let utf8_string = RString::new_utf8("⓯");
+    ///
+    /// assert_eq!(string.bytesize(), 13);
+    /// assert_eq!(utf8_string.bytesize(), 3);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'Hello, World!'
+    /// utf8_string = '⓯'
+    ///
+    /// string.bytesize == 13
+    /// utf8_string.bytesize == 3
+    /// ```
+    pub fn bytesize(&self) -> i64 {
+        string::bytesize(self.value())
+    }
+
+    /// Returns the number of characters in the string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Hello, World!");
+    /// let utf8_string = RString::new_utf8("⓯");
+    ///
+    /// assert_eq!(string.count_chars(), 13);
+    /// assert_eq!(utf8_string.count_chars(), 1);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'Hello, World!'
+    /// utf8_string = '⓯'
+    ///
+    /// string.length == 13
+    /// utf8_string.length == 1
+    /// ```
+    pub fn count_chars(&self) -> i64 {
+        string::count_chars(self.value())
+    }
+
+    /// Appends a given string slice onto the end of this String.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut string = RString::new_utf8("Hello, ");
+    /// string.concat("World!");
+    ///
+    /// assert_eq!(string.to_string(), "Hello, World!".to_string());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// str = 'Hello, '
+    /// str << 'World!'
+    ///
+    /// str == 'Hello, World!'
+    /// ```
+    pub fn concat(&mut self, string: &str) {
+        string::concat(self.value(), string.as_bytes());
+    }
+
+    /// Appends bytes onto the end of this String without checking their encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut string = RString::with_capacity(16);
+    ///
+    /// string.push_bytes(b"Hello, ");
+    /// string.push_bytes("World!".as_bytes());
+    ///
+    /// assert_eq!(string.to_str(), "Hello, World!");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// str = String.new(capacity: 16)
+    /// str << 'Hello, ' << 'World!'
+    ///
+    /// str == 'Hello, World!'
+    /// ```
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        string::concat(self.value(), bytes);
+    }
+
+    /// Returns the number of bytes the string can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::with_capacity(100);
+    ///
+    /// assert!(string.capacity() >= 100);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        string::capacity(self.value())
+    }
+
+    /// Reserves room for at least `additional` more bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut string = RString::new_utf8("Hello");
+    ///
+    /// string.reserve(100);
+    ///
+    /// assert!(string.capacity() >= 105);
+    /// assert_eq!(string.to_str(), "Hello");
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        string::reserve(self.value(), additional);
+    }
+
+    /// Sets the length of the string in bytes.
+    ///
+    /// # Safety
+    ///
+    /// `len` must not be greater than `capacity()` and the bytes up to `len` must be
+    /// initialized. Bytes beyond the current length are written through
+    /// `LockedBytes::spare_capacity_mut` before extending the string over them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut string = RString::with_capacity(5);
+    ///
+    /// {
+    ///     let mut bytes = string.as_mut_bytes().unwrap();
+    ///
+    ///     for (spare, byte) in bytes.spare_capacity_mut().iter_mut().zip(b"Hello") {
+    ///         spare.write(*byte);
+    ///     }
+    /// }
+    ///
+    /// unsafe { string.set_len(5) };
+    ///
+    /// assert_eq!(string.to_str(), "Hello");
+    ///
+    /// unsafe { string.set_len(4) };
+    ///
+    /// assert_eq!(string.to_str(), "Hell");
+    /// ```
+    pub unsafe fn set_len(&mut self, len: usize) {
+        string::modify(self.value());
+        string::set_len(self.value(), len);
+    }
+
+    /// Borrows the bytes of the string mutably without copying them.
+    ///
+    /// The string is locked while the returned guard is alive, so Ruby raises an error
+    /// instead of reallocating it. Returns `Err` with a `FrozenError` for a frozen string
+    /// or a `RuntimeError` for a string which is already locked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut string = RString::new_utf8("hello");
+    ///
+    /// {
+    ///     let mut bytes = string.as_mut_bytes().unwrap();
+    ///
+    ///     bytes.make_ascii_uppercase();
+    /// }
+    ///
+    /// assert_eq!(string.to_str(), "HELLO");
+    ///
+    /// let mut frozen = RString::new_utf8("frozen");
+    /// frozen.freeze();
+    ///
+    /// assert!(frozen.as_mut_bytes().is_err());
+    /// ```
+    pub fn as_mut_bytes(&mut self) -> Result<LockedBytes<'_>, AnyException> {
+        let value = self.value();
+
+        let result = VM::protect(|| {
+            string::modify(value);
+            string::locktmp(value);
+
+            NilClass::new().to_any_object()
+        });
+
+        match result {
+            Ok(_) => Ok(LockedBytes { string: self }),
+            Err(_) => {
+                let output = VM::error_info().unwrap();
+
+                // error cleanup
+                VM::clear_error_info();
+
+                Err(output)
+            }
+        }
+    }
+
+    /// Returns a substring of `length` characters starting at the character `start`.
+    ///
+    /// Characters are counted in the encoding of the string and a negative `start`
+    /// counts backwards from the end. Returns `None` when `start` is out of range.
     ///
     /// # Examples
     ///
@@ -157,27 +806,37 @@ impl RString {
     /// use rutie::{RString, VM};
     /// # VM::init();
     ///
-    /// let string = RString::new_utf8("Hello,\0World!");
+    /// let string = RString::new_utf8("łałała");
     ///
-    /// assert_eq!(string.to_string_unchecked(), "Hello,\0World!".to_string());
+    /// assert_eq!(string.substr(1, 3).unwrap().to_str(), "ała");
+    /// assert_eq!(string.substr(-2, 10).unwrap().to_str(), "ła");
+    /// assert!(string.substr(7, 1).is_none());
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// str = 'Hello,\0World!'
+    /// string = "łałała"
     ///
-    /// str == 'Hello,\0World!'
+    /// string[1, 3] == "ała"
+    /// string[-2, 10] == "ła"
+    /// string[7, 1] == nil
     /// ```
-    pub fn to_string_unchecked(&self) -> String {
-        string::value_to_string_unchecked(self.value())
+    pub fn substr(&self, start: i64, length: usize) -> Option<RString> {
+        let result = string::substr(self.value(), start, length as i64);
+
+        if result.is_nil() {
+            None
+        } else {
+            Some(RString::from(result))
+        }
     }
 
-    /// Retrieves `Vec<u8>` from Ruby `String` object.
-    ///
-    /// Unlike `to_string()` it does not perform any checks for internal null-bytes.
+    /// Returns the character index of the first occurrence of `pattern` at or after the
+    /// character `from`.
     ///
-    /// This function may be used to safely get binary data from Ruby.
+    /// Returns `Err` with an `Encoding::CompatibilityError` when the encodings of the
+    /// strings are not compatible.
     ///
     /// # Examples
     ///
@@ -185,15 +844,40 @@ impl RString {
     /// use rutie::{RString, VM};
     /// # VM::init();
     ///
-    /// let string = RString::new_utf8("Hello,\0World!");
+    /// let string = RString::new_utf8("łałała");
+    /// let pattern = RString::new_utf8("ła");
     ///
-    /// assert_eq!(string.to_vec_u8_unchecked(), (b"Hello,\0World!").to_vec());
+    /// assert_eq!(string.index(&pattern, 0), Ok(Some(0)));
+    /// assert_eq!(string.index(&pattern, 1), Ok(Some(2)));
+    /// assert_eq!(string.index(&RString::new_utf8("x"), 0), Ok(None));
     /// ```
-    pub fn to_vec_u8_unchecked(&self) -> Vec<u8> {
-        self.to_bytes_unchecked().to_vec()
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = "łałała"
+    ///
+    /// string.index("ła", 0) == 0
+    /// string.index("ła", 1) == 2
+    /// string.index("x", 0) == nil
+    /// ```
+    pub fn index(&self, pattern: &RString, from: i64) -> Result<Option<usize>, AnyException> {
+        let arguments = [pattern.to_any_object(), Integer::new(from).to_any_object()];
+
+        self.protect_send("index", &arguments).map(|result| {
+            if result.is_nil() {
+                None
+            } else {
+                Some(Integer::from(result.value()).to_u64() as usize)
+            }
+        })
     }
 
-    /// Retrieves underlying `&str` from Ruby `String` object.
+    /// Divides the string into substrings separated by `separator`.
+    ///
+    /// Trailing empty substrings are removed, like `String#split` does. Returns `Err`
+    /// with an `Encoding::CompatibilityError` when the encodings of the strings are not
+    /// compatible.
     ///
     /// # Examples
     ///
@@ -201,59 +885,57 @@ impl RString {
     /// use rutie::{RString, VM};
     /// # VM::init();
     ///
-    /// let string = RString::new_utf8("Hello, World!");
+    /// let string = RString::new_utf8("ła,ła,,");
+    /// let parts = string.split(&RString::new_utf8(",")).unwrap();
     ///
-    /// assert_eq!(string.to_str(), "Hello, World!");
+    /// assert_eq!(parts.to_vec::<String>(), Ok(vec!["ła".to_string(), "ła".to_string()]));
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// str = 'Hello, World!'
-    ///
-    /// str == 'Hello, World!'
+    /// "ła,ła,,".split(",") == ["ła", "ła"]
     /// ```
-    pub fn to_str(&self) -> &str {
-        let value = self.value();
-
-        string::value_to_str(value)
+    pub fn split(&self, separator: &RString) -> Result<Array, AnyException> {
+        self.protect_send("split", &[separator.to_any_object()])
+            .map(|result| Array::from(result.value()))
     }
 
-    /// Retrieves underlying `&str` from Ruby `String` object.
-    ///
-    /// Unlike `to_str()` it does not perform any checks for internal null-bytes.
-    ///
-    /// This function may be used to safely get binary data from Ruby.
+    /// Returns an iterator over the characters of the string in its encoding.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rutie::{RString, VM};
+    /// use rutie::{Encoding, RString, VM};
     /// # VM::init();
+    /// # VM::init_loadpath(); // Needed for alternate encodings
+    /// VM::require("enc/encdb");
     ///
-    /// let string = RString::new_utf8("Hello,\0World!");
+    /// let string = RString::from_bytes(b"a\x82\xa0b", &Encoding::find("Shift_JIS").unwrap());
     ///
-    /// assert_eq!(string.to_str_unchecked(), "Hello,\0World!");
+    /// let chars: Vec<Vec<u8>> = string
+    ///     .each_char()
+    ///     .map(|char| char.to_vec_u8_unchecked())
+    ///     .collect();
+    ///
+    /// assert_eq!(chars, vec![b"a".to_vec(), b"\x82\xa0".to_vec(), b"b".to_vec()]);
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// str = 'Hello,\0World!'
-    ///
-    /// str == 'Hello,\0World!'
+    /// "a\x82\xa0b".force_encoding("Shift_JIS").each_char.to_a.length == 3
     /// ```
-    pub fn to_str_unchecked(&self) -> &str {
-        let value = self.value();
+    pub fn each_char(&self) -> RStringIterator {
+        let result = unsafe { self.send("chars", &[]) };
 
-        string::value_to_str_unchecked(value)
+        RStringIterator::new(Array::from(result.value()))
     }
 
-    /// Retrieves underlying `&[u8]` from Ruby `String` object.
-    ///
-    /// Unlike `to_str()` it does not perform any checks for internal null-bytes.
+    /// Returns an iterator over the extended grapheme clusters of the string.
     ///
-    /// This function may be used to safely get binary data from Ruby.
+    /// Returns `Err` with an `ArgumentError` for a Unicode string with an invalid byte
+    /// sequence.
     ///
     /// # Examples
     ///
@@ -261,53 +943,57 @@ impl RString {
     /// use rutie::{RString, VM};
     /// # VM::init();
     ///
-    /// let string = RString::new_utf8("Hello,\0World!");
+    /// let string = RString::new_utf8("e\u{301}a");
     ///
-    /// assert_eq!(string.to_bytes_unchecked(), b"Hello,\0World!");
+    /// let clusters: Vec<String> = string
+    ///     .each_grapheme_cluster()
+    ///     .unwrap()
+    ///     .map(|cluster| cluster.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(clusters, vec!["e\u{301}", "a"]);
     /// ```
-    pub fn to_bytes_unchecked(&self) -> &[u8] {
-        let value = self.value();
-
-        string::value_to_bytes_unchecked(value)
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// "éa".each_grapheme_cluster.to_a == ["é", "a"]
+    /// ```
+    pub fn each_grapheme_cluster(&self) -> Result<RStringIterator, AnyException> {
+        self.protect_send("grapheme_clusters", &[])
+            .map(|result| RStringIterator::new(Array::from(result.value())))
     }
 
-    /// Returns an array of each characters codepoints.  This is useful as
-    /// a strings encoding determines where the codepoints are.
+    /// Returns a copy of the string with all characters mapped to uppercase.
+    ///
+    /// Uses the full Unicode case mapping for Unicode strings. Returns `Err` with an
+    /// `ArgumentError` for a string with an invalid byte sequence.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rutie::{Object, RString, Array, Fixnum, Encoding, EncodingSupport, VM};
+    /// use rutie::{RString, VM};
     /// # VM::init();
-    /// # VM::init_loadpath(); // Needed for alternate encodings
-    /// VM::require("enc/encdb");
-    /// VM::require("enc/trans/transdb");
-    ///
-    /// let string = RString::from_bytes(b"foo\x93_a", &Encoding::find("cp932").unwrap());
-    ///
-    /// let codepoints: Array = [102, 111, 111, 37727, 97].
-    ///   into_iter().map(|cp| Fixnum::new(cp as i64).to_any_object()).collect();
     ///
-    /// let codepoints2: Array = [0, 0, 0, 0, 0].
-    ///   into_iter().map(|cp| Fixnum::new(cp as i64).to_any_object()).collect();
+    /// let string = RString::new_utf8("straße");
     ///
-    /// assert_eq!(string.codepoints(), codepoints);
+    /// assert_eq!(string.upcase().unwrap().to_str(), "STRASSE");
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// str = "foo\x93_a".force_encoding("cp932")
-    ///
-    /// str.codepoints == [102, 111, 111, 37727, 97]
+    /// "straße".upcase == "STRASSE"
     /// ```
-    pub fn codepoints(&self) -> Array {
-        CodepointIterator::new(self)
-            .map(|n| Integer::new(n as i64).to_any_object())
-            .collect()
+    pub fn upcase(&self) -> Result<RString, AnyException> {
+        self.protect_send("upcase", &[])
+            .map(|result| RString::from(result.value()))
     }
 
-    /// Returns the length of the string in bytes
+    /// Returns a copy of the string with all characters mapped to lowercase.
+    ///
+    /// Uses the full Unicode case mapping for Unicode strings. Returns `Err` with an
+    /// `ArgumentError` for a string with an invalid byte sequence.
     ///
     /// # Examples
     ///
@@ -315,78 +1001,120 @@ impl RString {
     /// use rutie::{RString, VM};
     /// # VM::init();
     ///
-    /// let string = RString::new_utf8("Hello, World!");
-    /// let utf8_string = RString::new_utf8("⓯");
+    /// let string = RString::new_utf8("ŁAŁ");
     ///
-    /// assert_eq!(string.bytesize(), 13);
-    /// assert_eq!(utf8_string.bytesize(), 3);
+    /// assert_eq!(string.downcase().unwrap().to_str(), "łał");
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// string = 'Hello, World!'
-    /// utf8_string = '⓯'
-    ///
-    /// string.bytesize == 13
-    /// utf8_string.bytesize == 3
+    /// "ŁAŁ".downcase == "łał"
     /// ```
-    pub fn bytesize(&self) -> i64 {
-        string::bytesize(self.value())
+    pub fn downcase(&self) -> Result<RString, AnyException> {
+        self.protect_send("downcase", &[])
+            .map(|result| RString::from(result.value()))
     }
 
-    /// Returns the number of characters in the string
+    /// Compares the string to `other` ignoring case, using Unicode case folding.
+    ///
+    /// Returns `Ok(None)` when the encodings of the strings are not compatible and `Err`
+    /// with an `ArgumentError` for a string with an invalid byte sequence.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::cmp::Ordering;
+    ///
     /// use rutie::{RString, VM};
     /// # VM::init();
     ///
-    /// let string = RString::new_utf8("Hello, World!");
-    /// let utf8_string = RString::new_utf8("⓯");
+    /// let string = RString::new_utf8("Straße");
     ///
-    /// assert_eq!(string.count_chars(), 13);
-    /// assert_eq!(utf8_string.count_chars(), 1);
+    /// assert_eq!(string.casecmp(&RString::new_utf8("STRASSE")), Ok(Some(Ordering::Equal)));
+    /// assert_eq!(string.casecmp(&RString::new_utf8("STRAßF")), Ok(Some(Ordering::Less)));
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// string = 'Hello, World!'
-    /// utf8_string = '⓯'
-    ///
-    /// string.length == 13
-    /// utf8_string.length == 1
+    /// "Straße".casecmp?("STRASSE") == true
+    /// "Straße".downcase(:fold) <=> "STRAßF".downcase(:fold)
     /// ```
-    pub fn count_chars(&self) -> i64 {
-        string::count_chars(self.value())
+    pub fn casecmp(&self, other: &RString) -> Result<Option<Ordering>, AnyException> {
+        let equal = self.protect_send("casecmp?", &[other.to_any_object()])?;
+
+        if equal.is_nil() {
+            return Ok(None);
+        }
+
+        if equal.value().is_true() {
+            return Ok(Some(Ordering::Equal));
+        }
+
+        let fold = Symbol::new("fold").to_any_object();
+        let folded = self.protect_send("downcase", &[fold.clone()])?;
+        let other_folded = other.protect_send("downcase", &[fold])?;
+        let result = unsafe { folded.send("<=>", &[other_folded]) };
+
+        if Integer::from(result.value()).to_i64() < 0 {
+            Ok(Some(Ordering::Less))
+        } else {
+            Ok(Some(Ordering::Greater))
+        }
     }
 
-    /// Appends a given string slice onto the end of this String.
+    /// Returns a copy of the string with invalid byte sequences replaced.
+    ///
+    /// Without a `replacement` the Unicode replacement character or `?` is used, depending
+    /// on the encoding. Returns `Err` with an `Encoding::CompatibilityError` when the
+    /// encoding of `replacement` is not compatible with the string.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rutie::{RString, VM};
+    /// use rutie::{Encoding, RString, VM};
     /// # VM::init();
     ///
-    /// let mut string = RString::new_utf8("Hello, ");
-    /// string.concat("World!");
+    /// let string = RString::from_bytes(b"ab\xffc", &Encoding::utf8());
     ///
-    /// assert_eq!(string.to_string(), "Hello, World!".to_string());
+    /// assert_eq!(string.scrub(None).unwrap().to_str(), "ab\u{fffd}c");
+    /// assert_eq!(string.scrub(Some(&RString::new_utf8("*"))).unwrap().to_str(), "ab*c");
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// str = 'Hello, '
-    /// str << 'World!'
-    ///
-    /// str == 'Hello, World!'
+    /// "ab\xffc".scrub == "ab�c"
+    /// "ab\xffc".scrub("*") == "ab*c"
     /// ```
-    pub fn concat(&mut self, string: &str) {
-        string::concat(self.value(), string.as_bytes());
+    pub fn scrub(&self, replacement: Option<&RString>) -> Result<RString, AnyException> {
+        let replacement = match replacement {
+            Some(replacement) => replacement.value(),
+            None => NilClass::new().value(),
+        };
+
+        let value = self.value();
+
+        let result = VM::protect(|| AnyObject::from(string::scrub(value, replacement)));
+
+        match result {
+            // `nil` means there was nothing to replace
+            Ok(result) if result.is_nil() => Ok(RString::from(string::dup(value))),
+            Ok(result) => Ok(RString::from(result.value())),
+            Err(_) => {
+                let output = VM::error_info().unwrap();
+
+                // error cleanup
+                VM::clear_error_info();
+
+                Err(output)
+            }
+        }
+    }
+
+    fn is_writable(&self) -> bool {
+        !self.is_frozen() && !string::is_lockedtmp(self.value())
     }
 }
 
@@ -668,6 +1396,65 @@ impl From<RString> for AnyObject {
     }
 }
 
+#[cfg(unix)]
+fn bytes_to_os_string(bytes: Vec<u8>) -> Result<OsString, AnyException> {
+    Ok(OsString::from_vec(bytes))
+}
+
+#[cfg(not(unix))]
+fn bytes_to_os_string(bytes: Vec<u8>) -> Result<OsString, AnyException> {
+    String::from_utf8(bytes).map(OsString::from).map_err(|_| {
+        AnyException::new(
+            "EncodingError",
+            Some("Error converting to OsString, invalid UTF-8"),
+        )
+    })
+}
+
+/// Converts a `String` or an object responding to `to_path`, like `Pathname`, to a
+/// `PathBuf`.
+///
+/// Returns `Err` with a `TypeError` for other objects, or an `ArgumentError` for a
+/// path containing a null byte.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use std::path::PathBuf;
+///
+/// use rutie::{Fixnum, Object, VM};
+/// # VM::init();
+///
+/// VM::require("pathname");
+///
+/// let pathname = VM::eval("Pathname.new('/tmp').join('file.txt')").unwrap();
+///
+/// assert_eq!(PathBuf::try_from(pathname), Ok(PathBuf::from("/tmp/file.txt")));
+/// assert!(PathBuf::try_from(Fixnum::new(1).to_any_object()).is_err());
+/// ```
+impl TryFrom<AnyObject> for PathBuf {
+    type Error = AnyException;
+
+    fn try_from(object: AnyObject) -> Result<Self, Self::Error> {
+        let value = object.value();
+
+        let result = VM::protect(|| AnyObject::from(string::get_path(value)));
+
+        match result {
+            Ok(path) => RString::from(path.value()).to_path_buf(),
+            Err(_) => {
+                let output = VM::error_info().unwrap();
+
+                // error cleanup
+                VM::clear_error_info();
+
+                Err(output)
+            }
+        }
+    }
+}
+
 /// Implicit or `nil` conversion
 ///
 /// # Examples
@@ -719,6 +1506,150 @@ impl TryConvert<AnyObject> for RString {
     }
 }
 
+/// An iterator over substrings of an `RString`, returned by `RString::each_char` and
+/// `RString::each_grapheme_cluster`.
+pub struct RStringIterator {
+    items: ArrayIterator,
+}
+
+impl RStringIterator {
+    fn new(items: Array) -> RStringIterator {
+        RStringIterator {
+            items: items.into_iter(),
+        }
+    }
+}
+
+impl Iterator for RStringIterator {
+    type Item = RString;
+
+    fn next(&mut self) -> Option<RString> {
+        self.items.next().map(|item| RString::from(item.value()))
+    }
+}
+
+/// Mutable access to the bytes of a locked `RString`, returned by `RString::as_mut_bytes`.
+///
+/// The string is unlocked when the guard is dropped.
+pub struct LockedBytes<'a> {
+    string: &'a mut RString,
+}
+
+impl LockedBytes<'_> {
+    /// Returns the bytes between the length and the capacity of the string.
+    ///
+    /// They may be uninitialized. Initialize them here, drop the guard, then make them
+    /// part of the string with `RString::set_len`.
+    ///
+    /// See `RString::set_len` for an example.
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        let value = self.string.value();
+        let len = self.string.bytesize() as usize;
+        let capacity = string::capacity(value);
+
+        unsafe {
+            let spare = string::mut_ptr(value).add(len) as *mut MaybeUninit<u8>;
+
+            slice::from_raw_parts_mut(spare, capacity.saturating_sub(len))
+        }
+    }
+}
+
+impl Deref for LockedBytes<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.string.to_bytes_unchecked()
+    }
+}
+
+impl DerefMut for LockedBytes<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        let value = self.string.value();
+        let len = self.string.bytesize() as usize;
+
+        unsafe { slice::from_raw_parts_mut(string::mut_ptr(value), len) }
+    }
+}
+
+impl Drop for LockedBytes<'_> {
+    fn drop(&mut self) {
+        string::unlocktmp(self.string.value());
+    }
+}
+
+/// Allows `write!` and `format_args!` to write straight into a `String`.
+///
+/// Returns `Err` for a frozen or locked string.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+///
+/// use rutie::{RString, VM};
+/// # VM::init();
+///
+/// let mut string = RString::with_capacity(32);
+///
+/// write!(string, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+///
+/// assert_eq!(string.to_str(), "1 + 2 = 3");
+/// ```
+impl fmt::Write for RString {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !self.is_writable() {
+            return Err(fmt::Error);
+        }
+
+        self.push_bytes(s.as_bytes());
+
+        Ok(())
+    }
+}
+
+/// Allows a `String` to be used as a byte sink, for example by serializers.
+///
+/// Returns `Err` for a frozen or locked string.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+///
+/// use rutie::{Object, RString, VM};
+/// # VM::init();
+///
+/// let mut string = RString::with_capacity(32);
+///
+/// string.write_all(b"Hello, ").unwrap();
+/// string.write_all(b"World!").unwrap();
+///
+/// assert_eq!(string.to_str(), "Hello, World!");
+///
+/// string.freeze();
+///
+/// assert!(string.write_all(b"!").is_err());
+/// ```
+impl io::Write for RString {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !self.is_writable() {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "can't modify frozen or locked String",
+            ));
+        }
+
+        self.push_bytes(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 impl Object for RString {
     #[inline]
     fn value(&self) -> Value {
@@ -741,3 +1672,22 @@ impl PartialEq for RString {
         self.equals(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::{Encoding, EncodingSupport, RString};
+    use rb_sys_test_helpers::ruby_test;
+
+    #[cfg(unix)]
+    #[ruby_test]
+    fn test_path_round_trip_keeps_invalid_bytes() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+        let path = Path::new(OsStr::from_bytes(b"/tmp/\xff\xfe.txt"));
+        let string = RString::from_path(path);
+
+        assert_eq!(string.to_bytes_unchecked(), b"/tmp/\xff\xfe.txt");
+        assert_eq!(string.encoding(), Encoding::filesystem());
+        assert_eq!(string.to_path_buf().unwrap(), path);
+    }
+}
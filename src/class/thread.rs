@@ -1,11 +1,28 @@
-use std::convert::From;
+use std::{convert::From, time::Duration};
 
 use crate::{binding::thread, types::Value};
 
 #[cfg(unix)]
 use crate::types::RawFd;
 
-use crate::{AnyObject, Class, Object, VerifiedObject};
+use crate::{
+    AnyException, AnyObject, Boolean, Class, Float, Object, RString, Symbol, VerifiedObject, VM,
+};
+
+/// The status of a `Thread`, see `Thread::status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadStatus {
+    /// The thread is running or runnable
+    Run,
+    /// The thread is sleeping or waiting on I/O
+    Sleep,
+    /// The thread is being killed
+    Aborting,
+    /// The thread terminated normally
+    Finished,
+    /// The thread terminated with an exception
+    Failed,
+}
 
 /// `Thread`
 #[derive(Debug)]
@@ -70,6 +87,405 @@ impl Thread {
         thread::wait_fd(fd);
     }
 
+    /// Returns the currently running thread
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Thread, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Thread::current(), Thread::main());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Thread.current == Thread.main
+    /// ```
+    pub fn current() -> Self {
+        Self::from(thread::current())
+    }
+
+    /// Returns the main thread
+    ///
+    /// See `Thread::current` for an example.
+    pub fn main() -> Self {
+        Self::from(thread::main())
+    }
+
+    /// Gives other threads the chance to run
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Thread, VM};
+    /// # VM::init();
+    ///
+    /// Thread::pass();
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Thread.pass
+    /// ```
+    pub fn pass() {
+        thread::schedule();
+    }
+
+    /// Handles pending interrupts of the current thread
+    ///
+    /// Long running Rust loops should call this regularly, so `Thread#raise`,
+    /// `Thread#kill` and signal handlers such as Ctrl-C are processed. A
+    /// pending exception is raised from here and unwinds like any other Ruby
+    /// exception, use `VM::protect` if the loop needs to clean up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Thread, VM};
+    /// # VM::init();
+    ///
+    /// for _ in 0..1_000 {
+    ///     // do some work
+    ///
+    ///     Thread::check_interrupts();
+    /// }
+    /// ```
+    pub fn check_interrupts() {
+        thread::check_ints();
+    }
+
+    /// Waits for the thread to finish, at most `timeout` if given
+    ///
+    /// Returns the thread, or `nil` if the timeout expired. Returns an error
+    /// if the thread terminated with an exception.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rutie::{Fixnum, Object, Thread, VM};
+    /// # VM::init();
+    ///
+    /// let thread = Thread::new(|| Fixnum::new(1));
+    ///
+    /// let result = thread.join(Some(Duration::from_secs(5))).unwrap();
+    ///
+    /// assert!(!result.is_nil());
+    /// assert!(!thread.is_alive());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// thread = Thread.new { 1 }
+    ///
+    /// thread.join(5)
+    /// ```
+    pub fn join(&self, timeout: Option<Duration>) -> Result<AnyObject, AnyException> {
+        match timeout {
+            Some(timeout) => {
+                let timeout = Float::new(timeout.as_secs_f64()).to_any_object();
+
+                self.protect_send("join", &[timeout])
+            }
+            None => self.protect_send("join", &[]),
+        }
+    }
+
+    /// Waits for the thread to finish and returns its result
+    ///
+    /// This is Ruby's `Thread#value`, named differently because `Object::value`
+    /// returns the underlying `Value`. Returns an error if the thread terminated
+    /// with an exception.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Object, Thread, VM};
+    /// # VM::init();
+    ///
+    /// let thread = Thread::new(|| Fixnum::new(1 + 2));
+    ///
+    /// let value = thread.join_value().unwrap();
+    ///
+    /// assert_eq!(value.try_convert_to::<Fixnum>(), Ok(Fixnum::new(3)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Thread.new { 1 + 2 }.value == 3
+    /// ```
+    pub fn join_value(&self) -> Result<AnyObject, AnyException> {
+        self.protect_send("value", &[])
+    }
+
+    /// Terminates the thread
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{NilClass, Object, Thread, VM};
+    /// # VM::init();
+    ///
+    /// let thread = Thread::new(|| {
+    ///     let _ = VM::eval("sleep");
+    ///
+    ///     NilClass::new()
+    /// });
+    ///
+    /// thread.kill();
+    /// thread.join(None).unwrap();
+    ///
+    /// assert!(!thread.is_alive());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// thread.kill
+    /// ```
+    pub fn kill(&self) {
+        thread::kill(self.value());
+    }
+
+    /// Marks a sleeping thread as eligible for scheduling
+    ///
+    /// Returns an error if the thread is dead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Thread, VM};
+    /// # VM::init();
+    ///
+    /// let thread = Thread::new(|| Fixnum::new(1));
+    /// thread.join(None).unwrap();
+    ///
+    /// assert!(thread.wakeup().is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// thread.wakeup
+    /// ```
+    pub fn wakeup(&self) -> Result<(), AnyException> {
+        let value = self.value;
+
+        VM::protect(|| AnyObject::from(thread::wakeup(value)))
+            .map(|_| ())
+            .map_err(|_| {
+                let output = VM::error_info().unwrap();
+
+                // error cleanup
+                VM::clear_error_info();
+
+                output
+            })
+    }
+
+    /// Returns `true` if the thread is running or sleeping
+    ///
+    /// See `Thread::join` for an example.
+    pub fn is_alive(&self) -> bool {
+        unsafe { self.send("alive?", &[]) }.value().is_true()
+    }
+
+    /// Returns the status of the thread
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Thread, ThreadStatus, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Thread::current().status(), ThreadStatus::Run);
+    ///
+    /// let thread = Thread::new(|| Fixnum::new(1));
+    /// thread.join(None).unwrap();
+    ///
+    /// assert_eq!(thread.status(), ThreadStatus::Finished);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Thread.current.status == "run"
+    /// ```
+    pub fn status(&self) -> ThreadStatus {
+        let status = unsafe { self.send("status", &[]) };
+
+        if status.is_nil() {
+            return ThreadStatus::Failed;
+        }
+
+        match status.try_convert_to::<RString>() {
+            Ok(status) => match status.to_str() {
+                "run" => ThreadStatus::Run,
+                "sleep" => ThreadStatus::Sleep,
+                _ => ThreadStatus::Aborting,
+            },
+            Err(_) => ThreadStatus::Finished,
+        }
+    }
+
+    /// Returns the name of the thread
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Thread, VM};
+    /// # VM::init();
+    ///
+    /// let thread = Thread::new(|| Fixnum::new(1));
+    ///
+    /// assert_eq!(thread.name(), None);
+    ///
+    /// thread.set_name("worker");
+    ///
+    /// assert_eq!(thread.name(), Some("worker".to_string()));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// thread.name = "worker"
+    /// thread.name == "worker"
+    /// ```
+    pub fn name(&self) -> Option<String> {
+        let name = unsafe { self.send("name", &[]) };
+
+        name.try_convert_to::<RString>()
+            .ok()
+            .map(|name| name.to_string())
+    }
+
+    /// Sets the name of the thread
+    ///
+    /// See `Thread::name` for an example.
+    pub fn set_name(&self, name: &str) {
+        let name = RString::new_utf8(name).to_any_object();
+
+        unsafe { self.send("name=", &[name]) };
+    }
+
+    /// Returns `true` if an exception terminating the thread is reported on `$stderr`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Thread, VM};
+    /// # VM::init();
+    ///
+    /// let thread = Thread::current();
+    ///
+    /// thread.set_report_on_exception(false);
+    ///
+    /// assert!(!thread.report_on_exception());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Thread.current.report_on_exception = false
+    /// ```
+    pub fn report_on_exception(&self) -> bool {
+        unsafe { self.send("report_on_exception", &[]) }
+            .value()
+            .is_true()
+    }
+
+    /// Sets whether an exception terminating the thread is reported on `$stderr`
+    ///
+    /// See `Thread::report_on_exception` for an example.
+    pub fn set_report_on_exception(&self, report: bool) {
+        let report = Boolean::new(report).to_any_object();
+
+        unsafe { self.send("report_on_exception=", &[report]) };
+    }
+
+    /// Returns a fiber-local variable of the thread
+    ///
+    /// Fiber-local variables are what `Thread#[]` accesses in Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Object, Thread, VM};
+    /// # VM::init();
+    ///
+    /// let thread = Thread::current();
+    ///
+    /// assert!(thread.fiber_local("request").is_nil());
+    ///
+    /// thread.set_fiber_local("request", Fixnum::new(1));
+    ///
+    /// assert_eq!(thread.fiber_local("request").try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Thread.current[:request] = 1
+    /// Thread.current[:request] == 1
+    /// ```
+    pub fn fiber_local(&self, key: &str) -> AnyObject {
+        AnyObject::from(thread::local_aref(self.value(), key))
+    }
+
+    /// Sets a fiber-local variable of the thread
+    ///
+    /// See `Thread::fiber_local` for an example.
+    pub fn set_fiber_local<T: Object>(&self, key: &str, value: T) {
+        thread::local_aset(self.value(), key, value.value());
+    }
+
+    /// Returns a thread variable, shared by all fibers of the thread
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Object, Thread, VM};
+    /// # VM::init();
+    ///
+    /// let thread = Thread::current();
+    ///
+    /// assert!(thread.thread_variable("connections").is_nil());
+    ///
+    /// thread.set_thread_variable("connections", Fixnum::new(2));
+    ///
+    /// assert_eq!(
+    ///     thread.thread_variable("connections").try_convert_to::<Fixnum>(),
+    ///     Ok(Fixnum::new(2))
+    /// );
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Thread.current.thread_variable_set(:connections, 2)
+    /// Thread.current.thread_variable_get(:connections) == 2
+    /// ```
+    pub fn thread_variable(&self, key: &str) -> AnyObject {
+        let key = Symbol::new(key).to_any_object();
+
+        unsafe { self.send("thread_variable_get", &[key]) }
+    }
+
+    /// Sets a thread variable, shared by all fibers of the thread
+    ///
+    /// See `Thread::thread_variable` for an example.
+    pub fn set_thread_variable<T: Object>(&self, key: &str, value: T) {
+        let key = Symbol::new(key).to_any_object();
+
+        unsafe { self.send("thread_variable_set", &[key, value.to_any_object()]) };
+    }
+
     /// Release GVL for current thread.
     ///
     /// **Warning!** Due to MRI limitations, interaction with Ruby objects is not allowed while
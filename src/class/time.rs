@@ -0,0 +1,396 @@
+use std::{
+    convert::From,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    binding::time, types::Value, AnyObject, Boolean, Class, Integer, Object, RString,
+    VerifiedObject,
+};
+
+/// The UTC offset a `Time` is created with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeOffset {
+    /// The local timezone of the process, like `Time.at`.
+    Local,
+    /// UTC, like `Time.at(...).utc`.
+    Utc,
+    /// A fixed offset from UTC in seconds, like `Time.at(..., in: "+09:00")`.
+    ///
+    /// Ruby raises `ArgumentError` unless the offset is within one day.
+    Fixed(i32),
+}
+
+/// `Time`
+#[derive(Debug)]
+#[repr(C)]
+pub struct Time {
+    value: Value,
+}
+
+impl Time {
+    /// Returns the current time in the local timezone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Time, VM};
+    /// # VM::init();
+    ///
+    /// let now = Time::now();
+    ///
+    /// assert!(now.duration_since_epoch().is_some());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Time.now
+    /// ```
+    pub fn now() -> Self {
+        let time = unsafe { Class::from_existing("Time").send("now", &[]) };
+
+        Self::from(time.value())
+    }
+
+    /// Creates a new `Time` from a `SystemTime` without losing nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// use rutie::{Time, TimeOffset, VM};
+    /// # VM::init();
+    ///
+    /// let system_time = UNIX_EPOCH + Duration::new(1_500_000_000, 123_456_789);
+    /// let time = Time::new(system_time, TimeOffset::Fixed(9 * 3600));
+    ///
+    /// assert_eq!(time.to_system_time(), system_time);
+    /// assert_eq!(time.utc_offset(), 9 * 3600);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// time = Time.at(1_500_000_000, 123_456_789, :nsec, in: "+09:00")
+    ///
+    /// time.utc_offset == 32400
+    /// ```
+    pub fn new(system_time: SystemTime, offset: TimeOffset) -> Self {
+        let (secs, nanos) = match system_time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+            Err(error) => {
+                let duration = error.duration();
+                let secs = -(duration.as_secs() as i64);
+
+                match duration.subsec_nanos() {
+                    0 => (secs, 0),
+                    nanos => (secs - 1, 1_000_000_000 - nanos),
+                }
+            }
+        };
+
+        let value = match offset {
+            TimeOffset::Local => time::new_local(secs, nanos),
+            TimeOffset::Utc => time::new_utc(secs, nanos),
+            TimeOffset::Fixed(offset) => time::new_with_offset(secs, nanos, offset),
+        };
+
+        Self::from(value)
+    }
+
+    /// Creates a new `Time` from the `Duration` elapsed since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rutie::{Time, TimeOffset, VM};
+    /// # VM::init();
+    ///
+    /// let duration = Duration::new(1_500_000_000, 1);
+    /// let time = Time::from_duration(duration, TimeOffset::Utc);
+    ///
+    /// assert_eq!(time.duration_since_epoch(), Some(duration));
+    /// assert!(time.is_utc());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Time.at(1_500_000_000, 1, :nsec).utc
+    /// ```
+    pub fn from_duration(since_epoch: Duration, offset: TimeOffset) -> Self {
+        Self::new(UNIX_EPOCH + since_epoch, offset)
+    }
+
+    /// Converts the `Time` to a `SystemTime` without losing nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// use rutie::{Object, Time, VM};
+    /// # VM::init();
+    ///
+    /// let time = VM::eval("Time.at(-1, 500, :nsec)").unwrap()
+    ///     .try_convert_to::<Time>().unwrap();
+    ///
+    /// assert_eq!(time.to_system_time(), UNIX_EPOCH - Duration::new(0, 999_999_500));
+    /// ```
+    pub fn to_system_time(&self) -> SystemTime {
+        let (secs, nanos) = time::timespec(self.value());
+
+        if secs >= 0 {
+            UNIX_EPOCH + Duration::new(secs as u64, nanos)
+        } else {
+            UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+                + Duration::from_nanos(nanos.into())
+        }
+    }
+
+    /// Returns the `Duration` elapsed since the Unix epoch, or `None` for an earlier time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rutie::{Object, Time, VM};
+    /// # VM::init();
+    ///
+    /// let time = VM::eval("Time.at(10, 5, :nsec)").unwrap().try_convert_to::<Time>().unwrap();
+    ///
+    /// assert_eq!(time.duration_since_epoch(), Some(Duration::new(10, 5)));
+    ///
+    /// let time = VM::eval("Time.at(-10)").unwrap().try_convert_to::<Time>().unwrap();
+    ///
+    /// assert_eq!(time.duration_since_epoch(), None);
+    /// ```
+    pub fn duration_since_epoch(&self) -> Option<Duration> {
+        self.to_system_time().duration_since(UNIX_EPOCH).ok()
+    }
+
+    /// Returns the offset in seconds between the timezone of the time and UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, Time, VM};
+    /// # VM::init();
+    ///
+    /// let time = VM::eval("Time.at(0, in: '-05:30')").unwrap()
+    ///     .try_convert_to::<Time>().unwrap();
+    ///
+    /// assert_eq!(time.utc_offset(), -(5 * 3600 + 30 * 60));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Time.at(0, in: '-05:30').utc_offset == -19800
+    /// ```
+    pub fn utc_offset(&self) -> i32 {
+        time::utc_offset(self.value()) as i32
+    }
+
+    /// Returns `true` if the time is in UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::SystemTime;
+    ///
+    /// use rutie::{Time, TimeOffset, VM};
+    /// # VM::init();
+    ///
+    /// assert!(Time::new(SystemTime::now(), TimeOffset::Utc).is_utc());
+    /// assert!(!Time::new(SystemTime::now(), TimeOffset::Fixed(3600)).is_utc());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Time.now.utc.utc? == true
+    /// ```
+    pub fn is_utc(&self) -> bool {
+        let result = unsafe { self.send("utc?", &[]) };
+
+        Boolean::from(result.value()).to_bool()
+    }
+
+    /// Returns the name of the timezone, or `None` for a time with a fixed offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::SystemTime;
+    ///
+    /// use rutie::{Time, TimeOffset, VM};
+    /// # VM::init();
+    ///
+    /// let utc = Time::new(SystemTime::now(), TimeOffset::Utc);
+    /// let fixed = Time::new(SystemTime::now(), TimeOffset::Fixed(3600));
+    ///
+    /// assert_eq!(utc.zone(), Some("UTC".to_string()));
+    /// assert_eq!(fixed.zone(), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Time.now.utc.zone == "UTC"
+    /// Time.now.localtime("+01:00").zone == nil
+    /// ```
+    pub fn zone(&self) -> Option<String> {
+        let zone = unsafe { self.send("zone", &[]) };
+
+        if zone.is_nil() {
+            None
+        } else {
+            Some(RString::from(zone.value()).to_string())
+        }
+    }
+
+    /// Returns a new `Time` representing the same instant with a different UTC offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::SystemTime;
+    ///
+    /// use rutie::{Time, TimeOffset, VM};
+    /// # VM::init();
+    ///
+    /// let utc = Time::new(SystemTime::now(), TimeOffset::Utc);
+    /// let tokyo = utc.with_offset(TimeOffset::Fixed(9 * 3600));
+    ///
+    /// assert_eq!(tokyo.utc_offset(), 9 * 3600);
+    /// assert_eq!(tokyo.to_system_time(), utc.to_system_time());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Time.now.utc.getlocal("+09:00")
+    /// ```
+    pub fn with_offset(&self, offset: TimeOffset) -> Self {
+        let result = match offset {
+            TimeOffset::Local => unsafe { self.send("getlocal", &[]) },
+            TimeOffset::Utc => unsafe { self.send("getutc", &[]) },
+            TimeOffset::Fixed(offset) => {
+                let offset = Integer::from(offset).to_any_object();
+
+                unsafe { self.send("getlocal", &[offset]) }
+            }
+        };
+
+        Self::from(result.value())
+    }
+}
+
+impl From<Value> for Time {
+    fn from(value: Value) -> Self {
+        Time { value }
+    }
+}
+
+impl From<Time> for Value {
+    fn from(val: Time) -> Self {
+        val.value
+    }
+}
+
+impl From<Time> for AnyObject {
+    fn from(val: Time) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl From<SystemTime> for Time {
+    fn from(system_time: SystemTime) -> Self {
+        Time::new(system_time, TimeOffset::Local)
+    }
+}
+
+impl From<Time> for SystemTime {
+    fn from(time: Time) -> Self {
+        time.to_system_time()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::FixedOffset>> for Time {
+    fn from(datetime: chrono::DateTime<chrono::FixedOffset>) -> Self {
+        let offset = TimeOffset::Fixed(datetime.offset().local_minus_utc());
+
+        Time::new(SystemTime::from(datetime), offset)
+    }
+}
+
+/// Converts a `Time` to a `chrono::DateTime` keeping its UTC offset.
+///
+/// Requires the `chrono` feature.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use chrono::{DateTime, FixedOffset};
+/// use rutie::{Time, VM};
+/// # VM::init();
+///
+/// let datetime = DateTime::parse_from_rfc3339("2020-02-29T12:34:56.123456789+09:00").unwrap();
+/// let time = Time::from(datetime);
+///
+/// assert_eq!(time.utc_offset(), 9 * 3600);
+/// assert_eq!(DateTime::<FixedOffset>::try_from(time), Ok(datetime));
+/// ```
+#[cfg(feature = "chrono")]
+impl std::convert::TryFrom<Time> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = crate::AnyException;
+
+    fn try_from(source: Time) -> Result<Self, Self::Error> {
+        use crate::Exception;
+
+        let (secs, nanos) = time::timespec(source.value());
+
+        let offset = chrono::FixedOffset::east_opt(source.utc_offset());
+        let datetime = chrono::DateTime::from_timestamp(secs, nanos);
+
+        match (datetime, offset) {
+            (Some(datetime), Some(offset)) => Ok(datetime.with_timezone(&offset)),
+            _ => Err(crate::AnyException::new(
+                "RangeError",
+                Some("Time is out of range for chrono::DateTime"),
+            )),
+        }
+    }
+}
+
+impl Object for Time {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for Time {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        Class::from_existing("Time").case_equals(object)
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Time"
+    }
+}
+
+impl PartialEq for Time {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
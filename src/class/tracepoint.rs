@@ -0,0 +1,440 @@
+use std::{
+    convert::From,
+    ops::{BitOr, BitOrAssign},
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{
+    binding::{tracepoint, vm},
+    rubysys::tracepoint::{
+        EventFlag, TraceArgPtr, RUBY_EVENT_B_CALL, RUBY_EVENT_B_RETURN, RUBY_EVENT_CALL,
+        RUBY_EVENT_CLASS, RUBY_EVENT_C_CALL, RUBY_EVENT_C_RETURN, RUBY_EVENT_END,
+        RUBY_EVENT_FIBER_SWITCH, RUBY_EVENT_LINE, RUBY_EVENT_NONE, RUBY_EVENT_RAISE,
+        RUBY_EVENT_RETURN, RUBY_EVENT_THREAD_BEGIN, RUBY_EVENT_THREAD_END,
+    },
+    types::{c_void, Value},
+    wrappable_struct, AnyException, AnyObject, Class, Hash, Integer, NilClass, Object, RString,
+    Symbol, VerifiedObject, VM,
+};
+
+/// A set of events a `TracePoint` listens to
+///
+/// Events are combined with `|`.
+///
+/// # Examples
+///
+/// ```
+/// use rutie::TraceEvent;
+///
+/// let events = TraceEvent::CALL | TraceEvent::RETURN;
+///
+/// assert!(events.contains(TraceEvent::CALL));
+/// assert!(!events.contains(TraceEvent::LINE));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceEvent(EventFlag);
+
+impl TraceEvent {
+    /// No events
+    pub const NONE: TraceEvent = TraceEvent(RUBY_EVENT_NONE);
+    /// Execution of a new line
+    pub const LINE: TraceEvent = TraceEvent(RUBY_EVENT_LINE);
+    /// Start of a class or module definition
+    pub const CLASS: TraceEvent = TraceEvent(RUBY_EVENT_CLASS);
+    /// End of a class or module definition
+    pub const END: TraceEvent = TraceEvent(RUBY_EVENT_END);
+    /// Call of a Ruby method
+    pub const CALL: TraceEvent = TraceEvent(RUBY_EVENT_CALL);
+    /// Return from a Ruby method
+    pub const RETURN: TraceEvent = TraceEvent(RUBY_EVENT_RETURN);
+    /// Call of a C method
+    pub const C_CALL: TraceEvent = TraceEvent(RUBY_EVENT_C_CALL);
+    /// Return from a C method
+    pub const C_RETURN: TraceEvent = TraceEvent(RUBY_EVENT_C_RETURN);
+    /// An exception was raised
+    pub const RAISE: TraceEvent = TraceEvent(RUBY_EVENT_RAISE);
+    /// Start of a block
+    pub const B_CALL: TraceEvent = TraceEvent(RUBY_EVENT_B_CALL);
+    /// End of a block
+    pub const B_RETURN: TraceEvent = TraceEvent(RUBY_EVENT_B_RETURN);
+    /// Start of a thread
+    pub const THREAD_BEGIN: TraceEvent = TraceEvent(RUBY_EVENT_THREAD_BEGIN);
+    /// End of a thread
+    pub const THREAD_END: TraceEvent = TraceEvent(RUBY_EVENT_THREAD_END);
+    /// Switch to another fiber
+    pub const FIBER_SWITCH: TraceEvent = TraceEvent(RUBY_EVENT_FIBER_SWITCH);
+
+    /// Returns the raw `rb_event_flag_t` bits
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` if all events of `other` are also in `self`
+    pub const fn contains(self, other: TraceEvent) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if `self` and `other` have at least one event in common
+    pub const fn intersects(self, other: TraceEvent) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for TraceEvent {
+    type Output = TraceEvent;
+
+    fn bitor(self, other: TraceEvent) -> TraceEvent {
+        TraceEvent(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for TraceEvent {
+    fn bitor_assign(&mut self, other: TraceEvent) {
+        self.0 |= other.0;
+    }
+}
+
+/// Information about the event a `TracePoint` callback was called for
+///
+/// Only available inside the callback.
+pub struct TraceArg {
+    trace_arg: TraceArgPtr,
+}
+
+impl TraceArg {
+    /// Returns the event which is being traced
+    pub fn event(&self) -> TraceEvent {
+        TraceEvent(tracepoint::trace_arg_event_flag(self.trace_arg))
+    }
+
+    /// Returns the path of the file being executed
+    pub fn path(&self) -> Option<RString> {
+        let path = tracepoint::trace_arg_path(self.trace_arg);
+
+        if path.is_nil() {
+            None
+        } else {
+            Some(RString::from(path))
+        }
+    }
+
+    /// Returns the line number being executed
+    pub fn lineno(&self) -> i64 {
+        Integer::from(tracepoint::trace_arg_lineno(self.trace_arg)).to_i64()
+    }
+
+    /// Returns the name of the method being called or returned from
+    pub fn method_id(&self) -> Option<Symbol> {
+        let method_id = tracepoint::trace_arg_method_id(self.trace_arg);
+
+        if method_id.is_nil() {
+            None
+        } else {
+            Some(Symbol::from(method_id))
+        }
+    }
+
+    /// Returns the class or module which defines the method being called
+    pub fn defined_class(&self) -> Option<AnyObject> {
+        let defined_class = tracepoint::trace_arg_defined_class(self.trace_arg);
+
+        if defined_class.is_nil() {
+            None
+        } else {
+            Some(AnyObject::from(defined_class))
+        }
+    }
+
+    /// Returns `self` at the time of the event
+    pub fn self_object(&self) -> AnyObject {
+        AnyObject::from(tracepoint::trace_arg_self(self.trace_arg))
+    }
+
+    /// Returns the return value for `RETURN`, `C_RETURN` and `B_RETURN` events
+    pub fn return_value(&self) -> Option<AnyObject> {
+        let returns = TraceEvent::RETURN | TraceEvent::C_RETURN | TraceEvent::B_RETURN;
+
+        if self.event().intersects(returns) {
+            Some(AnyObject::from(tracepoint::trace_arg_return_value(
+                self.trace_arg,
+            )))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the exception for `RAISE` events
+    pub fn raised_exception(&self) -> Option<AnyException> {
+        if self.event().intersects(TraceEvent::RAISE) {
+            Some(AnyException::from(tracepoint::trace_arg_raised_exception(
+                self.trace_arg,
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+pub struct TraceCallback {
+    callback: Box<dyn FnMut(&TraceArg)>,
+}
+
+wrappable_struct!(TraceCallback, TraceCallbackWrapper, TRACE_CALLBACK_WRAPPER);
+
+// Hidden from Ruby because the name does not start with `@`
+const CALLBACK_IVAR: &str = "__rutie_trace_callback";
+
+extern "C" fn trace_callback(tracepoint: Value, data: *mut c_void) {
+    let data = unsafe { &mut *(data as *mut TraceCallback) };
+
+    let trace = TraceArg {
+        trace_arg: tracepoint::trace_arg(tracepoint),
+    };
+
+    (data.callback)(&trace);
+}
+
+/// `TracePoint`
+#[derive(Debug)]
+#[repr(C)]
+pub struct TracePoint {
+    value: Value,
+}
+
+impl TracePoint {
+    /// Creates a new disabled trace point calling `callback` for every event in `events`
+    ///
+    /// The callback is owned by the Ruby object and freed once it is garbage collected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// use rutie::{TraceEvent, TracePoint, VM};
+    /// # VM::init();
+    ///
+    /// let calls = Rc::new(RefCell::new(Vec::new()));
+    /// let recorded = calls.clone();
+    ///
+    /// let tracepoint = TracePoint::new(TraceEvent::C_CALL, move |trace| {
+    ///     if let Some(method_id) = trace.method_id() {
+    ///         recorded.borrow_mut().push(method_id.to_string());
+    ///     }
+    /// });
+    ///
+    /// assert!(!tracepoint.is_enabled());
+    ///
+    /// tracepoint.enabled(|| VM::eval("'hello'.upcase").unwrap());
+    ///
+    /// assert!(calls.borrow().iter().any(|method| method == "upcase"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// calls = []
+    ///
+    /// tracepoint = TracePoint.new(:c_call) do |trace|
+    ///   calls << trace.method_id.to_s
+    /// end
+    ///
+    /// tracepoint.enable { 'hello'.upcase }
+    /// ```
+    pub fn new<F>(events: TraceEvent, callback: F) -> Self
+    where
+        F: FnMut(&TraceArg) + 'static,
+    {
+        let data = TraceCallback {
+            callback: Box::new(callback),
+        };
+
+        let mut holder: AnyObject =
+            Class::from_existing("Object").wrap_data(data, &*TRACE_CALLBACK_WRAPPER);
+        let data = holder.get_data_mut(&*TRACE_CALLBACK_WRAPPER) as *mut TraceCallback;
+
+        let mut tracepoint = Self::from(tracepoint::new(
+            NilClass::new().value(),
+            events.bits(),
+            trace_callback,
+            data as *mut c_void,
+        ));
+
+        tracepoint.instance_variable_set(CALLBACK_IVAR, holder);
+
+        tracepoint
+    }
+
+    /// Starts calling the callback for all threads
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{TraceEvent, TracePoint, VM};
+    /// # VM::init();
+    ///
+    /// let tracepoint = TracePoint::new(TraceEvent::LINE, |_| {});
+    ///
+    /// tracepoint.enable();
+    /// assert!(tracepoint.is_enabled());
+    ///
+    /// tracepoint.disable();
+    /// assert!(!tracepoint.is_enabled());
+    /// ```
+    pub fn enable(&self) {
+        tracepoint::enable(self.value());
+    }
+
+    /// Stops calling the callback
+    ///
+    /// See `TracePoint::enable` for an example.
+    pub fn disable(&self) {
+        tracepoint::disable(self.value());
+    }
+
+    /// Returns `true` if the trace point is enabled
+    pub fn is_enabled(&self) -> bool {
+        tracepoint::is_enabled(self.value())
+    }
+
+    /// Enables the trace point only for events inside `target`
+    ///
+    /// `target` is a `Method`, `UnboundMethod` or `Proc`. Returns an error if
+    /// the trace point is already enabled or the target can not be traced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// use rutie::{TraceEvent, TracePoint, VM};
+    /// # VM::init();
+    ///
+    /// let target = VM::eval("def traced; end; def untraced; end; method(:traced)").unwrap();
+    ///
+    /// let calls = Rc::new(Cell::new(0));
+    /// let counter = calls.clone();
+    ///
+    /// let tracepoint = TracePoint::new(TraceEvent::CALL, move |_| counter.set(counter.get() + 1));
+    ///
+    /// tracepoint.enable_for(&target).unwrap();
+    ///
+    /// VM::eval("traced; untraced").unwrap();
+    ///
+    /// tracepoint.disable();
+    ///
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// tracepoint.enable(target: method(:traced))
+    /// ```
+    pub fn enable_for(&self, target: &impl Object) -> Result<(), AnyException> {
+        let mut keywords = Hash::new();
+        keywords.store(Symbol::new("target"), target.to_any_object());
+
+        let receiver = self.value();
+        let arguments = [keywords.value()];
+
+        VM::protect(|| {
+            let result = vm::call_method_with_keywords(receiver, "enable", &arguments);
+
+            AnyObject::from(result)
+        })
+        .map(|_| ())
+        .map_err(|_| {
+            let output = VM::error_info().unwrap();
+
+            // error cleanup
+            VM::clear_error_info();
+
+            output
+        })
+    }
+
+    /// Enables the trace point while running `func`
+    ///
+    /// The previous state is restored afterwards, also when `func` raises a
+    /// Ruby exception or panics, so tracing costs nothing outside of `func`.
+    /// The exception or panic is propagated after restoring.
+    ///
+    /// See `TracePoint::new` for an example.
+    pub fn enabled<F, R>(&self, func: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let was_enabled = self.is_enabled();
+
+        if !was_enabled {
+            self.enable();
+        }
+
+        let mut func = Some(func);
+        let mut output = None;
+
+        // Panics are caught inside, so they do not unwind through `rb_protect`
+        let result = VM::protect(|| {
+            if let Some(func) = func.take() {
+                output = Some(panic::catch_unwind(AssertUnwindSafe(func)));
+            }
+
+            NilClass::new().into()
+        });
+
+        if !was_enabled {
+            self.disable();
+        }
+
+        match result {
+            Ok(_) => match output {
+                Some(Ok(value)) => value,
+                Some(Err(payload)) => panic::resume_unwind(payload),
+                None => unreachable!(),
+            },
+            Err(state) => vm::jump_tag(state),
+        }
+    }
+}
+
+impl From<Value> for TracePoint {
+    fn from(value: Value) -> Self {
+        TracePoint { value }
+    }
+}
+
+impl From<TracePoint> for Value {
+    fn from(val: TracePoint) -> Self {
+        val.value
+    }
+}
+
+impl From<TracePoint> for AnyObject {
+    fn from(val: TracePoint) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for TracePoint {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for TracePoint {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.class() == Class::from_existing("TracePoint")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to TracePoint"
+    }
+}
+
+impl PartialEq for TracePoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
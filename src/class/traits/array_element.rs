@@ -0,0 +1,125 @@
+use crate::{
+    types::ValueType, AnyException, AnyObject, Boolean, Exception, Float, Integer, NilClass,
+    Object, RString, VerifiedObject, VM,
+};
+
+/// Conversion between Rust values and elements of a Ruby `Array`.
+///
+/// Used by `Array::to_vec` and `Array::from_slice` to convert whole arrays at once.
+/// Implemented for `i64`, `f64`, `bool`, `String` and every `VerifiedObject`.
+///
+/// # Examples
+///
+/// ```
+/// use rutie::{Array, VM};
+/// # VM::init();
+///
+/// let array = Array::from_slice(&[1.5, 2.5]);
+///
+/// assert_eq!(array.to_vec::<f64>(), Ok(vec![1.5, 2.5]));
+/// assert!(array.to_vec::<i64>().is_err());
+/// ```
+pub trait ArrayElement: Sized {
+    /// Converts an element of an array, returning `Err` with a `TypeError` when the
+    /// element has an unexpected type.
+    fn from_element(element: &AnyObject) -> Result<Self, AnyException>;
+
+    /// Converts the value to an object that can be stored in an array.
+    fn to_element(&self) -> AnyObject;
+}
+
+impl<T: VerifiedObject> ArrayElement for T {
+    fn from_element(element: &AnyObject) -> Result<Self, AnyException> {
+        element.try_convert_to::<T>()
+    }
+
+    fn to_element(&self) -> AnyObject {
+        self.to_any_object()
+    }
+}
+
+impl ArrayElement for i64 {
+    fn from_element(element: &AnyObject) -> Result<Self, AnyException> {
+        let value = element.value();
+
+        if value.is_fixnum() {
+            // Fixnums are tagged immediates, no need to call into Ruby
+            return Ok((value.value as i64) >> 1);
+        }
+
+        let integer = element.try_convert_to::<Integer>()?;
+        let mut result = 0;
+
+        // A Bignum raises `RangeError` when it does not fit into `i64`
+        VM::protect(|| {
+            result = integer.to_i64();
+
+            NilClass::new().to_any_object()
+        })
+        .map(|_| result)
+        .map_err(|_| {
+            let output = VM::error_info().unwrap();
+
+            // error cleanup
+            VM::clear_error_info();
+
+            output
+        })
+    }
+
+    fn to_element(&self) -> AnyObject {
+        Integer::new(*self).to_any_object()
+    }
+}
+
+impl ArrayElement for f64 {
+    fn from_element(element: &AnyObject) -> Result<Self, AnyException> {
+        let value = element.value();
+
+        if value.ty() == ValueType::Float {
+            return Ok(Float::from(value).to_f64());
+        }
+
+        if Integer::is_correct_type(element) {
+            return Float::implicit_to_f(element.clone()).map(|float| float.to_f64());
+        }
+
+        Err(AnyException::new(
+            "TypeError",
+            Some("Error converting to f64"),
+        ))
+    }
+
+    fn to_element(&self) -> AnyObject {
+        Float::new(*self).to_any_object()
+    }
+}
+
+impl ArrayElement for bool {
+    fn from_element(element: &AnyObject) -> Result<Self, AnyException> {
+        element
+            .try_convert_to::<Boolean>()
+            .map(|boolean| boolean.to_bool())
+    }
+
+    fn to_element(&self) -> AnyObject {
+        Boolean::new(*self).to_any_object()
+    }
+}
+
+impl ArrayElement for String {
+    fn from_element(element: &AnyObject) -> Result<Self, AnyException> {
+        let string = element.try_convert_to::<RString>()?;
+
+        String::from_utf8(string.to_vec_u8_unchecked()).map_err(|_| {
+            AnyException::new(
+                "EncodingError",
+                Some("Error converting to String, invalid UTF-8"),
+            )
+        })
+    }
+
+    fn to_element(&self) -> AnyObject {
+        RString::new_utf8(self).to_any_object()
+    }
+}
@@ -1,5 +1,7 @@
+pub mod array_element;
 pub mod encoding_support;
 pub mod exception;
+pub mod numeric;
 // Is good to have these long examples to be able to be copied and pasted as is.
 #[allow(clippy::needless_doctest_main)]
 pub mod object;
@@ -0,0 +1,74 @@
+use crate::{AnyException, AnyObject, Float, Object, Rational};
+
+/// Behaviour shared by Ruby's numeric types.
+///
+/// Implemented by `Fixnum`, `Integer`, `Float`, `Rational` and `Complex`, so a single
+/// function can accept any of them and convert it to the representation it needs.
+///
+/// # Examples
+///
+/// ```
+/// use rutie::{Float, Integer, Numeric, Rational, VM};
+/// # VM::init();
+///
+/// fn half<T: Numeric>(number: T) -> f64 {
+///     number.to_float().unwrap().to_f64() / 2.0
+/// }
+///
+/// assert_eq!(half(Integer::new(3)), 1.5);
+/// assert_eq!(half(Float::new(0.5)), 0.25);
+/// assert_eq!(half(Rational::new(1, 2)), 0.25);
+/// ```
+pub trait Numeric: Object {
+    /// Converts the number to a `Float`.
+    ///
+    /// Returns `Err` for a `Complex` with a non-zero imaginary part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Complex, Float, Integer, Numeric, Rational, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Rational::new(3, 4).to_float(), Ok(Float::new(0.75)));
+    ///
+    /// let complex = Complex::new(Integer::new(1), Integer::new(1));
+    ///
+    /// assert!(complex.to_float().is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Float(3/4r) == 0.75
+    /// ```
+    fn to_float(&self) -> Result<Float, AnyException> {
+        Float::implicit_to_f(AnyObject::from(self.value()))
+    }
+
+    /// Converts the number to an exact `Rational`.
+    ///
+    /// A `Float` is converted exactly, so `0.1` does not become `1/10`.
+    /// Returns `Err` for a `Complex` with a non-zero imaginary part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Float, Integer, Numeric, Rational, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Integer::new(5).to_rational(), Ok(Rational::new(5, 1)));
+    /// assert_eq!(Float::new(0.75).to_rational(), Ok(Rational::new(3, 4)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 5.to_r == 5/1r
+    /// 0.75.to_r == 3/4r
+    /// ```
+    fn to_rational(&self) -> Result<Rational, AnyException> {
+        self.protect_send("to_r", &[])
+            .map(|rational| Rational::from(rational.value()))
+    }
+}
@@ -1,13 +1,16 @@
-use std::convert::From;
+use std::{convert::From, slice};
 
 use crate::{
-    binding::{class, global::ValueType, vm},
+    binding::{class, enumerator, global::ValueType, method, vm},
     typed_data::DataTypeWrapper,
-    types::{Callback, Value},
+    types::{Argc, Callback, Value},
     util,
 };
 
-use crate::{AnyException, AnyObject, Class, Exception, NilClass, VerifiedObject, VM};
+use crate::{
+    AnyException, AnyObject, Array, Class, Enumerator, EnumeratorIter, Exception, Method, NilClass,
+    VerifiedObject, VM,
+};
 
 /// `Object`
 ///
@@ -844,6 +847,142 @@ pub trait Object: From<Value> {
         class::respond_to(self.value(), method)
     }
 
+    /// Looks up the named method and returns it bound to the object.
+    ///
+    /// Returns `Err` with a `NameError` when the object does not respond to the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("hello");
+    ///
+    /// let upcase = string.method("upcase").unwrap();
+    ///
+    /// assert_eq!(upcase.call(&[]), RString::new_utf8("HELLO").to_any_object());
+    /// assert!(string.method("something_else").is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// "hello".method(:upcase).call == "HELLO"
+    /// ```
+    fn method(&self, name: &str) -> Result<Method, AnyException> {
+        let result = VM::protect(|| AnyObject::from(method::object_method(self.value(), name)));
+
+        match result {
+            Ok(method) => Ok(Method::from(method.value())),
+            Err(_) => {
+                let output = VM::error_info().unwrap();
+
+                // error cleanup
+                VM::clear_error_info();
+
+                Err(output)
+            }
+        }
+    }
+
+    /// Returns a lazy iterator over the values yielded by the object's `each` method.
+    ///
+    /// The iterator wraps `rb_enumeratorize` for `:each`, so values are produced one
+    /// at a time and infinite `each` methods work. See `Enumerator::iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Integer, Object, VM};
+    /// # VM::init();
+    ///
+    /// let numbers = VM::eval("Class.new { def each; n = 0; loop { yield n += 1 }; end }.new")
+    ///     .unwrap();
+    ///
+    /// let values: Vec<i64> = numbers
+    ///     .enumerate()
+    ///     .take(3)
+    ///     .map(|value| value.unwrap().try_convert_to::<Integer>().unwrap().to_i64())
+    ///     .collect();
+    ///
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// numbers.each.take(3) == [1, 2, 3]
+    /// ```
+    fn enumerate(&self) -> EnumeratorIter {
+        let enumerator = enumerator::enumeratorize_with_size(self.value(), "each", &[], None);
+
+        Enumerator::from(enumerator).iter()
+    }
+
+    /// Iterates the object with its `each` method and collects the yielded values
+    /// into an `Array`.
+    ///
+    /// Iteration is internal, through `rb_block_call`, so it does not need a `Fiber`
+    /// like `Enumerator#next` does. It is eager: `each` runs to completion before
+    /// this returns, so it never returns for an infinite `each`. Use `enumerate`
+    /// to stream values instead. Values yielded together, like
+    /// the pairs of a `Hash`, are packed into an `Array`.
+    ///
+    /// Returns `Err` when `each` raises.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Integer, Object, VM};
+    /// # VM::init();
+    ///
+    /// let range = VM::eval("(1..4)").unwrap();
+    ///
+    /// let values: Vec<i64> = range.each_to_array().unwrap()
+    ///     .into_iter()
+    ///     .map(|value| value.try_convert_to::<Integer>().unwrap().to_i64())
+    ///     .collect();
+    ///
+    /// assert_eq!(values, vec![1, 2, 3, 4]);
+    ///
+    /// let hash = VM::eval("{ a: 1 }").unwrap();
+    /// let pair = hash.each_to_array().unwrap().at(0);
+    ///
+    /// assert_eq!(pair.try_convert_to::<Array>().unwrap().length(), 2);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// (1..4).each.to_a == [1, 2, 3, 4]
+    /// ```
+    fn each_to_array(&self) -> Result<Array, AnyException> {
+        let values = Array::new();
+
+        let result = VM::protect(|| {
+            AnyObject::from(vm::block_call(
+                self.value(),
+                "each",
+                &[],
+                collect_yielded,
+                values.value(),
+            ))
+        });
+
+        match result {
+            Ok(_) => Ok(values),
+            Err(_) => {
+                let output = VM::error_info().unwrap();
+
+                // error cleanup
+                VM::clear_error_info();
+
+                Err(output)
+            }
+        }
+    }
+
     /// `protect_send` returns Result<AnyObject, AnyObject>
     ///
     /// Protects against crash with `send` when exception object raised which will
@@ -1394,3 +1533,27 @@ where
         }
     }
 }
+
+// Block for `Object::each_to_array`, pushing the yielded values to the `Array` passed as `values`.
+extern "C" fn collect_yielded(
+    yielded: Value,
+    values: Value,
+    argc: Argc,
+    argv: *const Value,
+) -> Value {
+    let mut values = Array::from(values);
+
+    if argc > 1 {
+        let arguments = unsafe { slice::from_raw_parts(argv, argc as usize) };
+        let packed: Array = arguments
+            .iter()
+            .map(|value| AnyObject::from(*value))
+            .collect();
+
+        values.push(packed);
+    } else {
+        values.push(AnyObject::from(yielded));
+    }
+
+    NilClass::new().value()
+}
@@ -0,0 +1,201 @@
+use std::convert::From;
+
+use crate::{
+    class::method::source_location, types::Value, AnyException, AnyObject, Class, Integer, Method,
+    Module, Object, Symbol, VerifiedObject,
+};
+
+/// `UnboundMethod`
+///
+/// A method that is not bound to a receiver, as returned by `Method::unbind` or
+/// `Module#instance_method`.
+#[derive(Debug)]
+#[repr(C)]
+pub struct UnboundMethod {
+    value: Value,
+}
+
+impl UnboundMethod {
+    /// Binds the method to `receiver`.
+    ///
+    /// Returns `Err` with a `TypeError` when `receiver` is not a kind of the
+    /// owner of the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Array, Object, RString, UnboundMethod, VM};
+    /// # VM::init();
+    ///
+    /// let unbound = VM::eval("String.instance_method(:upcase)").unwrap()
+    ///     .try_convert_to::<UnboundMethod>().unwrap();
+    ///
+    /// let method = unbound.bind(&RString::new_utf8("hello")).unwrap();
+    ///
+    /// assert_eq!(method.call(&[]), RString::new_utf8("HELLO").to_any_object());
+    /// assert!(unbound.bind(&Array::new()).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// String.instance_method(:upcase).bind("hello").call == "HELLO"
+    /// ```
+    pub fn bind<T: Object>(&self, receiver: &T) -> Result<Method, AnyException> {
+        self.protect_send("bind", &[receiver.to_any_object()])
+            .map(|method| Method::from(method.value()))
+    }
+
+    /// Binds the method to `receiver` and calls it with given arguments.
+    ///
+    /// Returns `Err` when `receiver` can not be bound or the method raises.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Fixnum, Object, UnboundMethod, VM};
+    /// # VM::init();
+    ///
+    /// let unbound = VM::eval("Integer.instance_method(:+)").unwrap()
+    ///     .try_convert_to::<UnboundMethod>().unwrap();
+    ///
+    /// let result = unbound.bind_call(&Fixnum::new(1), &[Fixnum::new(2).to_any_object()]);
+    ///
+    /// assert_eq!(result, Ok(Fixnum::new(3).to_any_object()));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Integer.instance_method(:+).bind_call(1, 2) == 3
+    /// ```
+    pub fn bind_call<T: Object>(
+        &self,
+        receiver: &T,
+        arguments: &[AnyObject],
+    ) -> Result<AnyObject, AnyException> {
+        let mut bind_call_arguments = vec![receiver.to_any_object()];
+        bind_call_arguments.extend_from_slice(arguments);
+
+        self.protect_send("bind_call", &bind_call_arguments)
+    }
+
+    /// Returns the number of mandatory arguments, or `-n - 1` for a method
+    /// with `n` mandatory arguments that also takes optional ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, UnboundMethod, VM};
+    /// # VM::init();
+    ///
+    /// let unbound = VM::eval("Array.instance_method(:push)").unwrap()
+    ///     .try_convert_to::<UnboundMethod>().unwrap();
+    ///
+    /// assert_eq!(unbound.arity(), -1);
+    /// ```
+    pub fn arity(&self) -> i32 {
+        let arity = unsafe { self.send("arity", &[]) };
+
+        Integer::from(arity.value()).to_i32()
+    }
+
+    /// Returns the class or module that defines the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Module, Object, UnboundMethod, VM};
+    /// # VM::init();
+    ///
+    /// let unbound = VM::eval("Array.instance_method(:map)").unwrap()
+    ///     .try_convert_to::<UnboundMethod>().unwrap();
+    ///
+    /// assert_eq!(unbound.owner().value(), Module::from_existing("Array").value());
+    /// ```
+    pub fn owner(&self) -> Module {
+        let owner = unsafe { self.send("owner", &[]) };
+
+        Module::from(owner.value())
+    }
+
+    /// Returns the name of the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, UnboundMethod, VM};
+    /// # VM::init();
+    ///
+    /// let unbound = VM::eval("Array.instance_method(:push)").unwrap()
+    ///     .try_convert_to::<UnboundMethod>().unwrap();
+    ///
+    /// assert_eq!(unbound.name().to_str(), "push");
+    /// ```
+    pub fn name(&self) -> Symbol {
+        let name = unsafe { self.send("name", &[]) };
+
+        Symbol::from(name.value())
+    }
+
+    /// Returns the file and line where the method is defined, or `None` for a
+    /// method defined in C or Rust.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Object, UnboundMethod, VM};
+    /// # VM::init();
+    ///
+    /// let unbound = VM::eval("Array.instance_method(:push)").unwrap()
+    ///     .try_convert_to::<UnboundMethod>().unwrap();
+    ///
+    /// assert_eq!(unbound.source_location(), None);
+    /// ```
+    pub fn source_location(&self) -> Option<(String, u32)> {
+        let location = unsafe { self.send("source_location", &[]) };
+
+        source_location(location)
+    }
+}
+
+impl From<Value> for UnboundMethod {
+    fn from(value: Value) -> Self {
+        UnboundMethod { value }
+    }
+}
+
+impl From<UnboundMethod> for Value {
+    fn from(val: UnboundMethod) -> Self {
+        val.value
+    }
+}
+
+impl From<UnboundMethod> for AnyObject {
+    fn from(val: UnboundMethod) -> Self {
+        AnyObject::from(val.value)
+    }
+}
+
+impl Object for UnboundMethod {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl VerifiedObject for UnboundMethod {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        Class::from_existing("UnboundMethod").case_equals(object)
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to UnboundMethod"
+    }
+}
+
+impl PartialEq for UnboundMethod {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
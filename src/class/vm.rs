@@ -1,5 +1,19 @@
-use crate::{binding::vm, types::VmPointer};
-use crate::{util, AnyException, AnyObject, Array, Class, NilClass, Object, Proc, TryConvert};
+use crate::{
+    binding::{debug, vm},
+    types::VmPointer,
+};
+use crate::{
+    util, AnyException, AnyObject, Array, Class, NilClass, Object, Proc, ProfileFrame, TryConvert,
+};
+
+#[cfg(ruby_gte_3_0)]
+use crate::binding::ractor;
+
+#[cfg(ruby_gte_3_2)]
+use crate::Thread;
+
+#[cfg(ruby_gte_3_3)]
+use crate::PostponedJob;
 
 /// Virtual Machine and helpers
 pub struct VM;
@@ -826,6 +840,153 @@ impl VM {
 
         AnyObject::from(result)
     }
+    /// Declares whether the extension being initialized is Ractor-safe
+    ///
+    /// Call it with `true` at the start of the `Init_` function of an
+    /// extension which only defines Ractor-safe methods. Methods defined
+    /// afterwards can then be called from non-main Ractors. Available on
+    /// Ruby 3.0 and later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Class, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// #[allow(non_snake_case)]
+    /// #[no_mangle]
+    /// pub extern "C" fn Init_example() {
+    ///     VM::ext_ractor_safe(true);
+    ///
+    ///     Class::new("Example", None).define(|_klass| {
+    ///         // define Ractor-safe methods
+    ///     });
+    /// }
+    /// # Init_example();
+    /// ```
+    #[cfg(ruby_gte_3_0)]
+    pub fn ext_ractor_safe(flag: bool) {
+        ractor::ext_ractor_safe(flag);
+    }
+
+    /// Registers a job Ruby runs at the next safe point on a thread holding the GVL
+    ///
+    /// This is the building block for sampling profilers: a signal handler
+    /// can not touch the VM, but it can register a job which captures the
+    /// stack with `VM::profile_frames` once it is safe to do so.
+    ///
+    /// The job is taken by `'static` reference so registering it does not
+    /// allocate, which makes this function async-signal-safe. Registering the
+    /// same `job` reference again before it ran has no effect, different jobs
+    /// run independently even if they have the same type. Returns `false` if
+    /// 64 jobs are already pending or Ruby's job queue is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// use rutie::VM;
+    /// # VM::init();
+    ///
+    /// static RAN: AtomicBool = AtomicBool::new(false);
+    /// static JOB: fn() = || RAN.store(true, Ordering::SeqCst);
+    ///
+    /// assert!(VM::register_postponed_job(&JOB));
+    ///
+    /// VM::eval("10.times { }").unwrap();
+    ///
+    /// assert!(RAN.load(Ordering::SeqCst));
+    /// ```
+    pub fn register_postponed_job<F>(job: &'static F) -> bool
+    where
+        F: Fn() + Sync,
+    {
+        debug::register_postponed_job(job)
+    }
+
+    /// Registers a job once, to be triggered later with `PostponedJob::trigger`
+    ///
+    /// Must be called from a Ruby thread. Preregistering the same `job`
+    /// reference again returns the same `PostponedJob`. Returns `None` if no
+    /// more jobs can be registered. Available on Ruby 3.3 and later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// use rutie::VM;
+    /// # VM::init();
+    ///
+    /// static RUNS: AtomicUsize = AtomicUsize::new(0);
+    /// static JOB: fn() = || {
+    ///     RUNS.fetch_add(1, Ordering::SeqCst);
+    /// };
+    ///
+    /// let job = VM::preregister_postponed_job(&JOB).unwrap();
+    ///
+    /// job.trigger();
+    /// job.trigger();
+    ///
+    /// VM::eval("10.times { }").unwrap();
+    ///
+    /// assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+    /// ```
+    #[cfg(ruby_gte_3_3)]
+    pub fn preregister_postponed_job<F>(job: &'static F) -> Option<PostponedJob>
+    where
+        F: Fn() + Sync,
+    {
+        debug::preregister_postponed_job(job)
+            .map(|(index, handle)| PostponedJob::new(index, handle))
+    }
+
+    /// Captures up to `limit` frames of the current thread's Ruby stack
+    ///
+    /// The innermost frame comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::VM;
+    /// # VM::init();
+    ///
+    /// let frames = VM::profile_frames(16);
+    ///
+    /// assert!(frames.len() <= 16);
+    /// ```
+    pub fn profile_frames(limit: usize) -> Vec<ProfileFrame> {
+        debug::profile_frames(0, limit)
+            .into_iter()
+            .map(|(frame, line)| ProfileFrame::new(frame, line))
+            .collect()
+    }
+
+    /// Captures up to `limit` frames of the Ruby stack of `thread`
+    ///
+    /// Available on Ruby 3.2 and later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rutie::{Class, Object, Thread, VM};
+    /// # VM::init();
+    ///
+    /// let thread = unsafe { Class::from_existing("Thread").send("main", &[]) };
+    /// let thread = Thread::from(thread.value());
+    ///
+    /// let frames = VM::profile_thread_frames(&thread, 16);
+    ///
+    /// assert!(frames.len() <= 16);
+    /// ```
+    #[cfg(ruby_gte_3_2)]
+    pub fn profile_thread_frames(thread: &Thread, limit: usize) -> Vec<ProfileFrame> {
+        debug::profile_thread_frames(thread.value(), 0, limit)
+            .into_iter()
+            .map(|(frame, line)| ProfileFrame::new(frame, line))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -842,4 +1003,22 @@ mod tests {
 
         VM::at_exit(closure);
     }
+
+    #[ruby_test]
+    fn test_register_postponed_jobs_of_same_type() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        static FIRST_RAN: AtomicBool = AtomicBool::new(false);
+        static SECOND_RAN: AtomicBool = AtomicBool::new(false);
+        static FIRST: fn() = || FIRST_RAN.store(true, Ordering::SeqCst);
+        static SECOND: fn() = || SECOND_RAN.store(true, Ordering::SeqCst);
+
+        assert!(VM::register_postponed_job(&FIRST));
+        assert!(VM::register_postponed_job(&SECOND));
+
+        VM::eval("10.times { }").unwrap();
+
+        assert!(FIRST_RAN.load(Ordering::SeqCst));
+        assert!(SECOND_RAN.load(Ordering::SeqCst));
+    }
 }
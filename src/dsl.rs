@@ -418,6 +418,16 @@ macro_rules! methods {
 ///     server2.get_data(&*SERVER_WRAPPER); // <-- the same `SERVER_WRAPPER`
 ///     ```
 ///
+///  - (optional) `shareable` sets `RUBY_TYPED_FROZEN_SHAREABLE` on the data type (Ruby 3.0+),
+///    so frozen objects wrapping the struct can be passed between Ractors.
+///
+///    The struct has to be `Sync`, which is checked at compile time. Using `shareable`
+///    against Ruby older than 3.0 fails to compile. It must be the first optional argument:
+///
+///    ```ignore
+///    wrappable_struct!(Config, ConfigWrapper, CONFIG_WRAPPER, shareable);
+///    ```
+///
 ///  - (optional) `mark(data) { ... }` is a block which will be called during the "mark"
 ///    phase of garbage collection.
 ///
@@ -658,7 +668,7 @@ macro_rules! wrappable_struct {
             }
         }
     };
-    ($struct_name: ty, $wrapper: ident, $static_name: ident $($tail: tt)*) => {
+    (@define $struct_name: ty, $wrapper: ident, $static_name: ident, $flags: expr; $($tail: tt)*) => {
         pub struct $wrapper<T> {
             data_type: $crate::types::DataType,
             _marker: ::std::marker::PhantomData<T>,
@@ -678,12 +688,13 @@ macro_rules! wrappable_struct {
                 let reserved_bytes: [*mut $crate::types::c_void; 2] = [::std::ptr::null_mut(); 2];
 
                 let dmark = wrappable_struct!(@mark_function_pointer $($tail)*);
+                let flags: $crate::types::InternalValue = $flags;
 
                 let data_type = $crate::types::DataType {
                     wrap_struct_name: name.into_raw(),
                     parent: ::std::ptr::null(),
                     data: ::std::ptr::null_mut(),
-                    flags: $crate::types::Value::from(0).into(),
+                    flags: $crate::types::Value::from(flags),
 
                     function: $crate::types::DataTypeFunction {
                         dmark,
@@ -712,6 +723,23 @@ macro_rules! wrappable_struct {
             }
         }
     };
+    ($struct_name: ty, $wrapper: ident, $static_name: ident, shareable $($tail: tt)*) => {
+        // Shareable objects are accessed from several Ractors at once. The flag only
+        // exists from Ruby 3.0 on, so this fails to compile against older versions.
+        const _: fn() = || {
+            fn assert_sync<T: ?Sized + Sync>() {}
+
+            assert_sync::<$struct_name>();
+        };
+
+        wrappable_struct!(
+            @define $struct_name, $wrapper, $static_name,
+            $crate::rubysys::typed_data::RUBY_TYPED_FROZEN_SHAREABLE; $($tail)*
+        );
+    };
+    ($struct_name: ty, $wrapper: ident, $static_name: ident $($tail: tt)*) => {
+        wrappable_struct!(@define $struct_name, $wrapper, $static_name, 0; $($tail)*);
+    };
 }
 
 /// eval(string [, binding [, filename [,lineno]]]) → obj
@@ -762,3 +790,41 @@ macro_rules! eval {
         $crate::Class::from_existing("Kernel").protect_send("eval", arguments)
     }};
 }
+
+/// Returns a deduplicated frozen `RString` for a string literal, creating it only once.
+///
+/// The string is taken from Ruby's table of frozen strings on the first call and kept
+/// alive for the rest of the process, so hot methods can return constant strings without
+/// allocating. It is the Rust equivalent of a `# frozen_string_literal: true` literal.
+///
+/// # Examples
+///
+/// ```
+/// use rutie::{rstr, Object, RString, VM};
+/// # VM::init();
+///
+/// fn status() -> RString {
+///     rstr!("ok")
+/// }
+///
+/// assert_eq!(status().to_str(), "ok");
+/// assert!(status().is_frozen());
+/// assert_eq!(status().value(), status().value());
+/// assert_eq!(status().value(), RString::interned("ok").value());
+/// ```
+#[macro_export]
+macro_rules! rstr {
+    ($string:literal) => {{
+        static CACHE: ::std::sync::OnceLock<$crate::types::Value> = ::std::sync::OnceLock::new();
+
+        let value = *CACHE.get_or_init(|| {
+            let string = $crate::RString::interned($string);
+
+            $crate::GC::register_mark(&string);
+
+            $crate::Object::value(&string)
+        });
+
+        $crate::RString::from(value)
+    }};
+}
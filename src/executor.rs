@@ -0,0 +1,487 @@
+//! Running closures on a Ruby thread from native threads.
+//!
+//! Ruby objects may only be used by threads which Ruby created, and only while
+//! they hold the GVL. Threads spawned by `std::thread` or by async runtimes
+//! such as tokio can never acquire it.
+//!
+//! `RubyExecutor` starts a dedicated Ruby thread which runs closures sent
+//! to it from any thread. The Ruby thread sleeps in `Thread::wait_fd` on a
+//! pipe while there is no work, so other Ruby threads keep running and it
+//! does not spin. Results are returned through a `JobHandle`, which can be
+//! waited on or `.await`ed.
+
+use std::{
+    collections::VecDeque,
+    error, fmt,
+    future::Future,
+    io,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{Arc, Condvar, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use crate::{binding::vm, types::c_int, Exception, NilClass, Thread, VM};
+
+// Returns the tag of an interrupt which killed the executor thread while the
+// job ran
+type Job = Box<dyn FnOnce() -> Result<(), c_int> + Send>;
+
+/// An error returned by `JobHandle` when a job did not produce a value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutorError {
+    /// The executor stopped before the job ran, for example because its Ruby
+    /// thread was killed
+    Shutdown,
+    /// The job panicked
+    Panicked,
+    /// The job raised a Ruby exception, described by its `inspect` output
+    Exception(String),
+}
+
+impl fmt::Display for ExecutorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutorError::Shutdown => write!(f, "Ruby executor is shut down"),
+            ExecutorError::Panicked => write!(f, "job panicked"),
+            ExecutorError::Exception(exception) => write!(f, "job raised {}", exception),
+        }
+    }
+}
+
+impl error::Error for ExecutorError {}
+
+/// A handle for sending closures to a dedicated Ruby thread
+///
+/// Handles are cheap to clone and can be sent to and shared between any
+/// threads. The Ruby thread finishes once the last handle is dropped and all
+/// queued jobs ran.
+///
+/// Jobs run one after another with the GVL held. A Ruby exception raised or a
+/// panic in a job is reported through its `JobHandle` and does not stop the
+/// executor. Killing its Ruby thread, also from within a job, stops it and
+/// completes the running and queued jobs with `ExecutorError::Shutdown`.
+///
+/// # Examples
+///
+/// ```
+/// use rutie::{executor::RubyExecutor, Object, RString, Thread, VM};
+/// # VM::init();
+///
+/// let executor = RubyExecutor::new().unwrap();
+///
+/// let worker = std::thread::spawn(move || {
+///     let job = executor.spawn(|| {
+///         let string = unsafe { RString::new_utf8("ruby").send("upcase", &[]) };
+///
+///         string.try_convert_to::<RString>().unwrap().to_string()
+///     });
+///
+///     job.wait()
+/// });
+///
+/// let mut worker = Some(worker);
+///
+/// // Release the GVL so the executor thread can run the job
+/// let result = Thread::call_without_gvl(
+///     || worker.take().unwrap().join().unwrap(),
+///     Some(|| {}),
+/// );
+///
+/// assert_eq!(result, Ok("RUBY".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct RubyExecutor {
+    jobs: Arc<Mutex<JobQueue>>,
+    waker: Arc<PipeWaker>,
+}
+
+struct JobQueue {
+    jobs: VecDeque<Job>,
+    closed: bool,
+}
+
+impl RubyExecutor {
+    /// Starts a new Ruby thread which runs the jobs sent to this executor
+    ///
+    /// Must be called from a Ruby thread holding the GVL. Returns an error if
+    /// the wakeup pipe could not be created.
+    pub fn new() -> io::Result<Self> {
+        let (reader, writer) = pipe()?;
+
+        let jobs = Arc::new(Mutex::new(JobQueue {
+            jobs: VecDeque::new(),
+            closed: false,
+        }));
+
+        let executor = RubyExecutor {
+            jobs: jobs.clone(),
+            waker: Arc::new(PipeWaker { writer }),
+        };
+
+        let mut reader = Some(reader);
+
+        let thread = Thread::new(move || {
+            if let Some(reader) = reader.take() {
+                let result = run(&reader, &jobs);
+
+                close(&jobs);
+                drop(reader);
+
+                // Let the thread die if it was killed or an exception was raised in it
+                if let Err(state) = result {
+                    vm::jump_tag(state);
+                }
+            }
+
+            NilClass::new()
+        });
+
+        thread.set_name("rutie executor");
+
+        Ok(executor)
+    }
+
+    /// Queues `func` to run on the executor's Ruby thread
+    ///
+    /// Returns immediately. The result is available through the returned
+    /// `JobHandle`.
+    ///
+    /// See `RubyExecutor` for an example.
+    pub fn spawn<F, R>(&self, func: F) -> JobHandle<R>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let completion = Arc::new(Completion::new());
+        let completer = Completer(Some(completion.clone()));
+
+        // A killed job drops its completer, completing it with `Shutdown`
+        let job: Job = Box::new(move || {
+            completer.complete(run_job(func)?);
+
+            Ok(())
+        });
+
+        {
+            let mut queue = self.jobs.lock().unwrap();
+
+            // Dropping the job completes it with `ExecutorError::Shutdown`
+            if queue.closed {
+                return JobHandle { completion };
+            }
+
+            queue.jobs.push_back(job);
+        }
+
+        self.waker.wake();
+
+        JobHandle { completion }
+    }
+
+    /// Runs `func` on the executor's Ruby thread and waits for its result
+    ///
+    /// Blocks the current thread, see `JobHandle::wait`.
+    pub fn block_on<F, R>(&self, func: F) -> Result<R, ExecutorError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.spawn(func).wait()
+    }
+}
+
+impl fmt::Debug for RubyExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RubyExecutor")
+            .field("fd", &self.waker.writer.as_raw_fd())
+            .finish()
+    }
+}
+
+/// The result of a job sent to a `RubyExecutor`
+///
+/// Either block on it with `wait` or `.await` it from async code.
+pub struct JobHandle<R> {
+    completion: Arc<Completion<R>>,
+}
+
+impl<R> JobHandle<R> {
+    /// Blocks the current thread until the job finished
+    ///
+    /// **Warning!** The job needs the GVL to run. Waiting from a Ruby thread
+    /// holding the GVL, including the executor's thread itself, deadlocks.
+    /// Release it first with `Thread::call_without_gvl`.
+    pub fn wait(self) -> Result<R, ExecutorError> {
+        let mut slot = self.completion.slot.lock().unwrap();
+
+        loop {
+            if let Some(result) = slot.result.take() {
+                return result;
+            }
+
+            slot = self.completion.condvar.wait(slot).unwrap();
+        }
+    }
+
+    /// Returns `true` if the job finished
+    pub fn is_finished(&self) -> bool {
+        self.completion.slot.lock().unwrap().result.is_some()
+    }
+}
+
+impl<R> Future for JobHandle<R> {
+    type Output = Result<R, ExecutorError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.completion.slot.lock().unwrap();
+
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<R> fmt::Debug for JobHandle<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JobHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+struct Completion<R> {
+    slot: Mutex<Slot<R>>,
+    condvar: Condvar,
+}
+
+struct Slot<R> {
+    result: Option<Result<R, ExecutorError>>,
+    waker: Option<Waker>,
+}
+
+impl<R> Completion<R> {
+    fn new() -> Self {
+        Completion {
+            slot: Mutex::new(Slot {
+                result: None,
+                waker: None,
+            }),
+            condvar: Condvar::new(),
+        }
+    }
+}
+
+// Completes the job with `ExecutorError::Shutdown` if it is dropped without
+// running, so waiting handles never hang.
+struct Completer<R>(Option<Arc<Completion<R>>>);
+
+impl<R> Completer<R> {
+    fn complete(mut self, result: Result<R, ExecutorError>) {
+        if let Some(completion) = self.0.take() {
+            finish(&completion, result);
+        }
+    }
+}
+
+impl<R> Drop for Completer<R> {
+    fn drop(&mut self) {
+        if let Some(completion) = self.0.take() {
+            finish(&completion, Err(ExecutorError::Shutdown));
+        }
+    }
+}
+
+fn finish<R>(completion: &Completion<R>, result: Result<R, ExecutorError>) {
+    let waker = {
+        let mut slot = completion.slot.lock().unwrap();
+
+        slot.result = Some(result);
+        slot.waker.take()
+    };
+
+    completion.condvar.notify_all();
+
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+// Runs `func` on the executor thread, catching Ruby exceptions and panics so
+// neither unwinds through the other's frames. Returns the tag of a non-exception
+// interrupt, for example `Thread#kill`, which must be re-raised.
+fn run_job<F, R>(func: F) -> Result<Result<R, ExecutorError>, c_int>
+where
+    F: FnOnce() -> R,
+{
+    let mut func = Some(func);
+    let mut output = None;
+
+    let result = VM::protect(|| {
+        if let Some(func) = func.take() {
+            output = Some(panic::catch_unwind(AssertUnwindSafe(func)));
+        }
+
+        NilClass::new().into()
+    });
+
+    match result {
+        Ok(_) => match output {
+            Some(Ok(value)) => Ok(Ok(value)),
+            _ => Ok(Err(ExecutorError::Panicked)),
+        },
+        Err(state) => {
+            // Killing the thread leaves a fixnum tag instead of an exception,
+            // which `jump_tag` needs untouched
+            let exception = VM::error_info().map_err(|_| state)?;
+
+            // error cleanup
+            VM::clear_error_info();
+
+            Ok(Err(ExecutorError::Exception(exception.inspect())))
+        }
+    }
+}
+
+// The loop of the executor's Ruby thread, returns the tag of an interrupt
+// raised while waiting or running a job, for example by `Thread#kill`
+fn run(reader: &OwnedFd, jobs: &Mutex<JobQueue>) -> Result<(), c_int> {
+    let fd = reader.as_raw_fd();
+
+    loop {
+        VM::protect(|| {
+            Thread::wait_fd(fd);
+
+            NilClass::new().into()
+        })?;
+
+        let open = drain(reader);
+
+        // Pop before running, so jobs may spawn further jobs without deadlocking
+        loop {
+            let job = jobs.lock().unwrap().jobs.pop_front();
+
+            match job {
+                Some(job) => job()?,
+                None => break,
+            }
+        }
+
+        if !open {
+            return Ok(());
+        }
+    }
+}
+
+// Stops accepting jobs, queued jobs are dropped and complete with `Shutdown`
+fn close(jobs: &Mutex<JobQueue>) {
+    let queued = {
+        let mut queue = jobs.lock().unwrap();
+
+        queue.closed = true;
+        std::mem::take(&mut queue.jobs)
+    };
+
+    drop(queued);
+}
+
+struct PipeWaker {
+    writer: OwnedFd,
+}
+
+impl PipeWaker {
+    fn wake(&self) {
+        let byte = 1u8;
+
+        // A full pipe is already readable, so a failed write loses nothing
+        unsafe { libc::write(self.writer.as_raw_fd(), &byte as *const u8 as *const _, 1) };
+    }
+}
+
+// Reads all pending wakeups, returns `false` once every writer is closed
+fn drain(reader: &OwnedFd) -> bool {
+    let mut buffer = [0u8; 64];
+
+    loop {
+        let read = unsafe {
+            libc::read(
+                reader.as_raw_fd(),
+                buffer.as_mut_ptr() as *mut _,
+                buffer.len(),
+            )
+        };
+
+        match read {
+            0 => return false,
+            read if read > 0 => continue,
+            _ => match io::Error::last_os_error().kind() {
+                io::ErrorKind::Interrupted => continue,
+                io::ErrorKind::WouldBlock => return true,
+                _ => return false,
+            },
+        }
+    }
+}
+
+fn pipe() -> io::Result<(OwnedFd, OwnedFd)> {
+    let mut fds = [0; 2];
+
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let (reader, writer) = unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) };
+
+    for fd in &[&reader, &writer] {
+        let fd = fd.as_raw_fd();
+
+        unsafe {
+            let flags = libc::fcntl(fd, libc::F_GETFL);
+
+            if flags < 0
+                || libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) < 0
+                || libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) < 0
+            {
+                return Err(io::Error::last_os_error());
+            }
+        }
+    }
+
+    Ok((reader, writer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExecutorError, RubyExecutor};
+    use crate::Thread;
+    use rb_sys_test_helpers::ruby_test;
+
+    #[ruby_test]
+    fn test_kill_executor_thread_from_job() {
+        let executor = RubyExecutor::new().unwrap();
+        let killed = executor.spawn(|| Thread::current().kill());
+
+        let mut waiting = Some((executor, killed));
+
+        // Release the GVL so the executor thread can run the jobs
+        let results = Thread::call_without_gvl(
+            || {
+                let (executor, killed) = waiting.take().unwrap();
+
+                (killed.wait(), executor.block_on(|| ()))
+            },
+            Some(|| {}),
+        );
+
+        assert_eq!(
+            results,
+            (Err(ExecutorError::Shutdown), Err(ExecutorError::Shutdown))
+        );
+    }
+}
@@ -9,20 +9,41 @@ pub mod rubysys;
 #[macro_use]
 pub mod dsl;
 
+pub mod alloc;
+#[cfg(unix)]
+pub mod executor;
 pub mod typed_data;
 pub mod types;
 pub mod util;
 
 pub use crate::class::{
-    any_exception::AnyException, any_object::AnyObject, array::Array, binding::Binding,
-    boolean::Boolean, class::Class, encoding::Encoding, enumerator::Enumerator, fixnum::Fixnum,
-    float::Float, gc::GC, hash::Hash, integer::Integer, module::Module, nil_class::NilClass,
-    rproc::Proc, string::RString, symbol::Symbol, thread::Thread, vm::VM,
+    any_exception::AnyException, any_object::AnyObject, array::Array, array::ArrayIterator,
+    binding::Binding, boolean::Boolean, class::Class, complex::Complex,
+    condition_variable::ConditionVariable, encoding::Encoding, encoding_converter::ConversionError,
+    encoding_converter::ConversionErrorKind, encoding_converter::EncodingConverter,
+    enumerator::Enumerator, enumerator::EnumeratorIter, fiber::Fiber, fixnum::Fixnum, float::Float,
+    gc::GcEvent, gc::GcEventHook, gc::GcStats, gc::GC, hash::Hash, hash::HashIterator,
+    integer::Integer, io::IOReader, io::IO, match_data::MatchData, method::Method, module::Module,
+    mutex::RMutex, nil_class::NilClass, profile_frame::ProfileFrame, queue::Queue,
+    queue::SizedQueue, range::Range, rational::Rational, regexp::Regexp, rproc::Proc,
+    ruby_io::IOStream, ruby_io::RubyIO, string::LockedBytes, string::RString,
+    string::RStringIterator, symbol::Symbol, thread::Thread, thread::ThreadStatus, time::Time,
+    time::TimeOffset, tracepoint::TraceArg, tracepoint::TraceEvent, tracepoint::TracePoint,
+    unbound_method::UnboundMethod, vm::VM,
 };
 
+#[cfg(ruby_gte_3_1)]
+pub use crate::class::fiber_scheduler::FiberScheduler;
+
+#[cfg(ruby_gte_3_3)]
+pub use crate::class::postponed_job::PostponedJob;
+
+#[cfg(ruby_gte_3_0)]
+pub use crate::class::ractor::{Ractor, RactorLocalKey};
+
 pub use crate::class::traits::{
-    encoding_support::EncodingSupport, exception::Exception, object::Object,
-    try_convert::TryConvert, verified_object::VerifiedObject,
+    array_element::ArrayElement, encoding_support::EncodingSupport, exception::Exception,
+    numeric::Numeric, object::Object, try_convert::TryConvert, verified_object::VerifiedObject,
 };
 
 pub use crate::helpers::codepoint_iterator::CodepointIterator;
@@ -1,16 +1,25 @@
 use super::types::{c_long, Value};
 
 extern "C" {
+    // VALUE
+    // rb_ary_clear(VALUE ary)
+    pub fn rb_ary_clear(array: Value) -> Value;
     // VALUE
     // rb_ary_concat(VALUE x, VALUE y)
     pub fn rb_ary_concat(array: Value, other_array: Value) -> Value;
     // VALUE
+    // rb_ary_delete_at(VALUE ary, long pos)
+    pub fn rb_ary_delete_at(array: Value, pos: c_long) -> Value;
+    // VALUE
     // rb_ary_dup(VALUE ary)
     pub fn rb_ary_dup(array: Value) -> Value;
     // VALUE
     // rb_ary_entry(VALUE ary, long offset)
     pub fn rb_ary_entry(array: Value, offset: c_long) -> Value;
     // VALUE
+    // rb_ary_includes(VALUE ary, VALUE item)
+    pub fn rb_ary_includes(array: Value, item: Value) -> Value;
+    // VALUE
     // rb_ary_join(VALUE ary, VALUE sep)
     pub fn rb_ary_join(array: Value, separator: Value) -> Value;
     // VALUE
@@ -29,6 +38,9 @@ extern "C" {
     // rb_ary_push(VALUE ary, VALUE item)
     pub fn rb_ary_push(array: Value, item: Value) -> Value;
     // VALUE
+    // rb_ary_replace(VALUE copy, VALUE orig)
+    pub fn rb_ary_replace(copy: Value, orig: Value) -> Value;
+    // VALUE
     // rb_ary_reverse(VALUE ary)
     pub fn rb_ary_reverse(array: Value) -> Value;
     // VALUE
@@ -44,6 +56,9 @@ extern "C" {
     // rb_ary_store(VALUE ary, long idx, VALUE val)
     pub fn rb_ary_store(array: Value, index: c_long, item: Value);
     // VALUE
+    // rb_ary_subseq(VALUE ary, long beg, long len)
+    pub fn rb_ary_subseq(array: Value, beg: c_long, len: c_long) -> Value;
+    // VALUE
     // rb_ary_to_s(VALUE ary)
     pub fn rb_ary_to_s(array: Value) -> Value;
     // VALUE
@@ -54,3 +69,7 @@ extern "C" {
 pub unsafe fn rb_ary_len(value: Value) -> c_long {
     rb_sys::RARRAY_LEN(value) as c_long
 }
+
+pub unsafe fn rb_ary_const_ptr(value: Value) -> *const Value {
+    rb_sys::RARRAY_CONST_PTR(value) as *const Value
+}
@@ -0,0 +1,13 @@
+use super::types::Value;
+
+extern "C" {
+    // VALUE
+    // rb_complex_new(VALUE x, VALUE y)
+    pub fn rb_complex_new(real: Value, imag: Value) -> Value;
+    // VALUE
+    // rb_complex_real(VALUE self)
+    pub fn rb_complex_real(complex: Value) -> Value;
+    // VALUE
+    // rb_complex_imag(VALUE self)
+    pub fn rb_complex_imag(complex: Value) -> Value;
+}
@@ -0,0 +1,66 @@
+use super::types::{c_int, c_uint, c_void, Value};
+
+// void (*rb_postponed_job_func_t)(void *arg)
+pub type PostponedJobFunction = extern "C" fn(*mut c_void);
+
+// rb_postponed_job_handle_t
+#[cfg(ruby_gte_3_3)]
+pub type PostponedJobHandle = c_uint;
+
+#[cfg(ruby_gte_3_3)]
+pub const POSTPONED_JOB_HANDLE_INVALID: PostponedJobHandle = c_uint::MAX;
+
+extern "C" {
+    // int
+    // rb_postponed_job_register_one(unsigned int flags, rb_postponed_job_func_t func, void *data)
+    pub fn rb_postponed_job_register_one(
+        flags: c_uint,
+        func: PostponedJobFunction,
+        data: *mut c_void,
+    ) -> c_int;
+    // rb_postponed_job_handle_t
+    // rb_postponed_job_preregister(unsigned int flags, rb_postponed_job_func_t func, void *data)
+    #[cfg(ruby_gte_3_3)]
+    pub fn rb_postponed_job_preregister(
+        flags: c_uint,
+        func: PostponedJobFunction,
+        data: *mut c_void,
+    ) -> PostponedJobHandle;
+    // void
+    // rb_postponed_job_trigger(rb_postponed_job_handle_t h)
+    #[cfg(ruby_gte_3_3)]
+    pub fn rb_postponed_job_trigger(handle: PostponedJobHandle);
+    // VALUE
+    // rb_profile_frame_classpath(VALUE frame)
+    pub fn rb_profile_frame_classpath(frame: Value) -> Value;
+    // VALUE
+    // rb_profile_frame_full_label(VALUE frame)
+    pub fn rb_profile_frame_full_label(frame: Value) -> Value;
+    // VALUE
+    // rb_profile_frame_label(VALUE frame)
+    pub fn rb_profile_frame_label(frame: Value) -> Value;
+    // VALUE
+    // rb_profile_frame_method_name(VALUE frame)
+    pub fn rb_profile_frame_method_name(frame: Value) -> Value;
+    // VALUE
+    // rb_profile_frame_path(VALUE frame)
+    pub fn rb_profile_frame_path(frame: Value) -> Value;
+    // int
+    // rb_profile_frames(int start, int limit, VALUE *buff, int *lines)
+    pub fn rb_profile_frames(
+        start: c_int,
+        limit: c_int,
+        buff: *mut Value,
+        lines: *mut c_int,
+    ) -> c_int;
+    // int
+    // rb_profile_thread_frames(VALUE thread, int start, int limit, VALUE *buff, int *lines)
+    #[cfg(ruby_gte_3_2)]
+    pub fn rb_profile_thread_frames(
+        thread: Value,
+        start: c_int,
+        limit: c_int,
+        buff: *mut Value,
+        lines: *mut c_int,
+    ) -> c_int;
+}
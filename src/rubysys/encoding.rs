@@ -1,6 +1,9 @@
 use super::{
     constant::{FL_USER_8, FL_USER_9},
-    types::{c_char, c_int, size_t, EncodingIndex, EncodingType, InternalValue, RBasic, Value},
+    types::{
+        c_char, c_int, c_uchar, c_void, size_t, EncodingIndex, EncodingType, InternalValue, RBasic,
+        Value,
+    },
 };
 
 pub const ENC_DUMMY_FLAG: isize = 1 << 24;
@@ -12,6 +15,26 @@ pub const ENC_CODERANGE_BROKEN: isize = FL_USER_8 | FL_USER_9;
 pub const ENC_CODERANGE_MASK: isize =
     ENC_CODERANGE_7BIT | ENC_CODERANGE_VALID | ENC_CODERANGE_BROKEN;
 
+pub const ECONV_INVALID_REPLACE: c_int = 0x0000_0002;
+pub const ECONV_UNDEF_REPLACE: c_int = 0x0000_0020;
+pub const ECONV_UNDEF_HEX_CHARREF: c_int = 0x0000_0030;
+pub const ECONV_UNIVERSAL_NEWLINE_DECORATOR: c_int = 0x0000_0100;
+pub const ECONV_CRLF_NEWLINE_DECORATOR: c_int = 0x0000_1000;
+pub const ECONV_CR_NEWLINE_DECORATOR: c_int = 0x0000_2000;
+pub const ECONV_PARTIAL_INPUT: c_int = 0x0002_0000;
+
+// rb_econv_result_t
+pub const ECONV_INVALID_BYTE_SEQUENCE: c_int = 0;
+pub const ECONV_UNDEFINED_CONVERSION: c_int = 1;
+pub const ECONV_DESTINATION_BUFFER_FULL: c_int = 2;
+pub const ECONV_SOURCE_BUFFER_EMPTY: c_int = 3;
+pub const ECONV_FINISHED: c_int = 4;
+pub const ECONV_AFTER_OUTPUT: c_int = 5;
+pub const ECONV_INCOMPLETE_INPUT: c_int = 6;
+
+// rb_econv_t *
+pub type EconvPtr = *mut c_void;
+
 extern "C" {
     // VALUE
     // rb_enc_associate(VALUE obj, rb_encoding *enc)
@@ -85,6 +108,40 @@ extern "C" {
     // int
     // rb_econv_prepare_opts(VALUE opthash, VALUE *opts)
     pub fn rb_econv_prepare_opts(opthash: Value, opts: *const Value) -> c_int;
+    // rb_econv_t *
+    // rb_econv_open(const char *source_encoding, const char *destination_encoding, int ecflags)
+    pub fn rb_econv_open(
+        source_encoding: *const c_char,
+        destination_encoding: *const c_char,
+        ecflags: c_int,
+    ) -> EconvPtr;
+    // rb_econv_result_t
+    // rb_econv_convert(rb_econv_t *ec,
+    //                  const unsigned char **source_buffer_ptr, const unsigned char *source_buffer_end,
+    //                  unsigned char **destination_buffer_ptr, unsigned char *destination_buffer_end,
+    //                  int flags)
+    pub fn rb_econv_convert(
+        ec: EconvPtr,
+        source_buffer_ptr: *mut *const c_uchar,
+        source_buffer_end: *const c_uchar,
+        destination_buffer_ptr: *mut *mut c_uchar,
+        destination_buffer_end: *mut c_uchar,
+        flags: c_int,
+    ) -> c_int;
+    // int
+    // rb_econv_set_replacement(rb_econv_t *ec, const unsigned char *str, size_t len, const char *encname)
+    pub fn rb_econv_set_replacement(
+        ec: EconvPtr,
+        str: *const c_uchar,
+        len: size_t,
+        encname: *const c_char,
+    ) -> c_int;
+    // VALUE
+    // rb_econv_make_exception(rb_econv_t *ec)
+    pub fn rb_econv_make_exception(ec: EconvPtr) -> Value;
+    // void
+    // rb_econv_close(rb_econv_t *ec)
+    pub fn rb_econv_close(ec: EconvPtr);
     // unsigned int
     // rb_enc_codepoint_len(const char *p, const char *e, int *len_p, rb_encoding *enc)
     pub fn rb_enc_codepoint_len(
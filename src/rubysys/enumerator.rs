@@ -0,0 +1,18 @@
+use super::types::{c_int, Value};
+
+// VALUE
+// rb_enumerator_size_func(VALUE recv, VALUE argv, VALUE eobj)
+pub type EnumeratorSizeFunction = extern "C" fn(Value, Value, Value) -> Value;
+
+extern "C" {
+    // VALUE
+    // rb_enumeratorize_with_size(VALUE obj, VALUE meth, int argc, const VALUE *argv,
+    //                            rb_enumerator_size_func *size_fn)
+    pub fn rb_enumeratorize_with_size(
+        object: Value,
+        method: Value,
+        argc: c_int,
+        argv: *const Value,
+        size_fn: Option<EnumeratorSizeFunction>,
+    ) -> Value;
+}
@@ -0,0 +1,56 @@
+use super::types::{Argc, Value};
+
+// VALUE (*rb_block_call_func_t)(VALUE yielded_arg, VALUE callback_arg, int argc,
+//                               const VALUE *argv, VALUE blockarg)
+pub type BlockCallFunction = extern "C" fn(Value, Value, Argc, *const Value, Value) -> Value;
+
+extern "C" {
+    // VALUE
+    // rb_fiber_alive_p(VALUE fiber)
+    pub fn rb_fiber_alive_p(fiber: Value) -> Value;
+    // VALUE
+    // rb_fiber_current(void)
+    pub fn rb_fiber_current() -> Value;
+    // VALUE
+    // rb_fiber_new(rb_block_call_func_t func, VALUE callback_obj)
+    pub fn rb_fiber_new(func: BlockCallFunction, callback_obj: Value) -> Value;
+    // VALUE
+    // rb_fiber_resume(VALUE fiber, int argc, const VALUE *argv)
+    pub fn rb_fiber_resume(fiber: Value, argc: Argc, argv: *const Value) -> Value;
+    #[cfg(ruby_gte_3_1)]
+    // VALUE
+    // rb_fiber_scheduler_block(VALUE scheduler, VALUE blocker, VALUE timeout)
+    pub fn rb_fiber_scheduler_block(scheduler: Value, blocker: Value, timeout: Value) -> Value;
+    #[cfg(ruby_gte_3_1)]
+    // VALUE
+    // rb_fiber_scheduler_current(void)
+    pub fn rb_fiber_scheduler_current() -> Value;
+    #[cfg(ruby_gte_3_1)]
+    // VALUE
+    // rb_fiber_scheduler_io_wait(VALUE scheduler, VALUE io, VALUE events, VALUE timeout)
+    pub fn rb_fiber_scheduler_io_wait(
+        scheduler: Value,
+        io: Value,
+        events: Value,
+        timeout: Value,
+    ) -> Value;
+    #[cfg(ruby_gte_3_1)]
+    // VALUE
+    // rb_fiber_scheduler_io_wait_readable(VALUE scheduler, VALUE io)
+    pub fn rb_fiber_scheduler_io_wait_readable(scheduler: Value, io: Value) -> Value;
+    #[cfg(ruby_gte_3_1)]
+    // VALUE
+    // rb_fiber_scheduler_io_wait_writable(VALUE scheduler, VALUE io)
+    pub fn rb_fiber_scheduler_io_wait_writable(scheduler: Value, io: Value) -> Value;
+    #[cfg(ruby_gte_3_1)]
+    // VALUE
+    // rb_fiber_scheduler_kernel_sleep(VALUE scheduler, VALUE duration)
+    pub fn rb_fiber_scheduler_kernel_sleep(scheduler: Value, duration: Value) -> Value;
+    #[cfg(ruby_gte_3_1)]
+    // VALUE
+    // rb_fiber_scheduler_unblock(VALUE scheduler, VALUE blocker, VALUE fiber)
+    pub fn rb_fiber_scheduler_unblock(scheduler: Value, blocker: Value, fiber: Value) -> Value;
+    // VALUE
+    // rb_fiber_yield(int argc, const VALUE *argv)
+    pub fn rb_fiber_yield(argc: Argc, argv: *const Value) -> Value;
+}
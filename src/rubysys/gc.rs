@@ -1,6 +1,9 @@
 use super::types::{c_int, size_t, ssize_t, CallbackPtr, Value};
 
 extern "C" {
+    // int
+    // rb_during_gc(void)
+    pub fn rb_during_gc() -> c_int;
     // void
     // rb_gc_adjust_memory_usage(ssize_t diff)
     pub fn rb_gc_adjust_memory_usage(diff: ssize_t);
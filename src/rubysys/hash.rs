@@ -20,6 +20,9 @@ extern "C" {
     // rb_hash_foreach(VALUE hash, int (*func)(ANYARGS), VALUE farg)
     pub fn rb_hash_foreach(hash: Value, callback: CallbackPtr, pass: CallbackMutPtr);
     // VALUE
+    // rb_hash_lookup2(VALUE hash, VALUE key, VALUE def)
+    pub fn rb_hash_lookup2(hash: Value, key: Value, default: Value) -> Value;
+    // VALUE
     // rb_hash_new(void)
     pub fn rb_hash_new() -> Value;
     // VALUE
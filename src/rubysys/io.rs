@@ -0,0 +1,13 @@
+use super::types::Value;
+
+extern "C" {
+    // VALUE
+    // rb_io_check_io(VALUE io)
+    pub fn rb_io_check_io(io: Value) -> Value;
+    // VALUE
+    // rb_io_flush(VALUE io)
+    pub fn rb_io_flush(io: Value) -> Value;
+    // VALUE
+    // rb_io_write(VALUE io, VALUE str)
+    pub fn rb_io_write(io: Value, string: Value) -> Value;
+}
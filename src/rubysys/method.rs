@@ -0,0 +1,18 @@
+use super::types::{Argc, Value};
+
+extern "C" {
+    // VALUE
+    // rb_obj_method(VALUE obj, VALUE vid)
+    pub fn rb_obj_method(object: Value, name: Value) -> Value;
+    // VALUE
+    // rb_method_call(int argc, const VALUE *argv, VALUE method)
+    pub fn rb_method_call(argc: Argc, argv: *const Value, method: Value) -> Value;
+    // VALUE
+    // rb_method_call_with_block(int argc, const VALUE *argv, VALUE method, VALUE passed_procval)
+    pub fn rb_method_call_with_block(
+        argc: Argc,
+        argv: *const Value,
+        method: Value,
+        pass_procval: Value,
+    ) -> Value;
+}
@@ -0,0 +1,29 @@
+use super::types::Value;
+
+extern "C" {
+    // VALUE
+    // rb_mutex_lock(VALUE mutex)
+    pub fn rb_mutex_lock(mutex: Value) -> Value;
+    // VALUE
+    // rb_mutex_locked_p(VALUE mutex)
+    pub fn rb_mutex_locked_p(mutex: Value) -> Value;
+    // VALUE
+    // rb_mutex_new(void)
+    pub fn rb_mutex_new() -> Value;
+    // VALUE
+    // rb_mutex_sleep(VALUE self, VALUE timeout)
+    pub fn rb_mutex_sleep(mutex: Value, timeout: Value) -> Value;
+    // VALUE
+    // rb_mutex_synchronize(VALUE mutex, VALUE (*func)(VALUE arg), VALUE arg)
+    pub fn rb_mutex_synchronize(
+        mutex: Value,
+        func: extern "C" fn(Value) -> Value,
+        arg: Value,
+    ) -> Value;
+    // VALUE
+    // rb_mutex_trylock(VALUE mutex)
+    pub fn rb_mutex_trylock(mutex: Value) -> Value;
+    // VALUE
+    // rb_mutex_unlock(VALUE mutex)
+    pub fn rb_mutex_unlock(mutex: Value) -> Value;
+}
@@ -0,0 +1,25 @@
+use super::types::{c_void, Value};
+
+// rb_ractor_local_key_t
+pub type RactorLocalKeyPtr = *mut c_void;
+
+extern "C" {
+    // void
+    // rb_ext_ractor_safe(bool flag)
+    pub fn rb_ext_ractor_safe(flag: bool);
+    // VALUE
+    // rb_ractor_make_shareable(VALUE obj)
+    pub fn rb_ractor_make_shareable(obj: Value) -> Value;
+    // VALUE
+    // rb_ractor_make_shareable_copy(VALUE obj)
+    pub fn rb_ractor_make_shareable_copy(obj: Value) -> Value;
+    // bool
+    // rb_ractor_local_storage_value_lookup(rb_ractor_local_key_t key, VALUE *val)
+    pub fn rb_ractor_local_storage_value_lookup(key: RactorLocalKeyPtr, val: *mut Value) -> bool;
+    // rb_ractor_local_key_t
+    // rb_ractor_local_storage_value_newkey(void)
+    pub fn rb_ractor_local_storage_value_newkey() -> RactorLocalKeyPtr;
+    // void
+    // rb_ractor_local_storage_value_set(rb_ractor_local_key_t key, VALUE val)
+    pub fn rb_ractor_local_storage_value_set(key: RactorLocalKeyPtr, val: Value);
+}
@@ -0,0 +1,24 @@
+use super::types::{c_int, c_long, Value};
+
+extern "C" {
+    // VALUE
+    // rb_range_new(VALUE beg, VALUE end, int exclude_end)
+    pub fn rb_range_new(beg: Value, end: Value, exclude_end: c_int) -> Value;
+    // int
+    // rb_range_values(VALUE range, VALUE *begp, VALUE *endp, int *exclp)
+    pub fn rb_range_values(
+        range: Value,
+        begp: *mut Value,
+        endp: *mut Value,
+        exclp: *mut c_int,
+    ) -> c_int;
+    // VALUE
+    // rb_range_beg_len(VALUE range, long *begp, long *lenp, long len, int err)
+    pub fn rb_range_beg_len(
+        range: Value,
+        begp: *mut c_long,
+        lenp: *mut c_long,
+        len: c_long,
+        err: c_int,
+    ) -> Value;
+}
@@ -0,0 +1,13 @@
+use super::types::Value;
+
+extern "C" {
+    // VALUE
+    // rb_rational_new(VALUE num, VALUE den)
+    pub fn rb_rational_new(num: Value, den: Value) -> Value;
+    // VALUE
+    // rb_rational_num(VALUE rat)
+    pub fn rb_rational_num(rat: Value) -> Value;
+    // VALUE
+    // rb_rational_den(VALUE rat)
+    pub fn rb_rational_den(rat: Value) -> Value;
+}
@@ -0,0 +1,28 @@
+use super::types::{c_int, Value};
+
+pub const ONIG_OPTION_IGNORECASE: c_int = 1;
+pub const ONIG_OPTION_EXTEND: c_int = 2;
+pub const ONIG_OPTION_MULTILINE: c_int = 4;
+pub const ARG_ENCODING_FIXED: c_int = 16;
+pub const ARG_ENCODING_NONE: c_int = 32;
+
+extern "C" {
+    // VALUE
+    // rb_reg_new_str(VALUE s, int options)
+    pub fn rb_reg_new_str(string: Value, options: c_int) -> Value;
+    // int
+    // rb_reg_options(VALUE re)
+    pub fn rb_reg_options(regexp: Value) -> c_int;
+    // VALUE
+    // rb_reg_quote(VALUE str)
+    pub fn rb_reg_quote(string: Value) -> Value;
+    // VALUE
+    // rb_reg_nth_match(int nth, VALUE match)
+    pub fn rb_reg_nth_match(nth: c_int, match_data: Value) -> Value;
+    // VALUE
+    // rb_reg_match_pre(VALUE match)
+    pub fn rb_reg_match_pre(match_data: Value) -> Value;
+    // VALUE
+    // rb_reg_match_post(VALUE match)
+    pub fn rb_reg_match_post(match_data: Value) -> Value;
+}
@@ -1,6 +1,6 @@
 use super::{
     constant::FL_USER_7,
-    types::{c_char, c_long, EncodingType, InternalValue, Value},
+    types::{c_char, c_long, size_t, EncodingType, InternalValue, Value},
 };
 use rb_sys::{RString, VALUE};
 pub const STR_TMPLOCK: isize = FL_USER_7;
@@ -66,6 +66,38 @@ extern "C" {
     // VALUE
     // rb_str_new_frozen(VALUE orig)
     pub fn rb_str_new_frozen(orig: Value) -> Value;
+    // VALUE
+    // rb_get_path(VALUE obj)
+    pub fn rb_get_path(obj: Value) -> Value;
+    // VALUE
+    // rb_str_dup(VALUE str)
+    pub fn rb_str_dup(str: Value) -> Value;
+    // VALUE
+    // rb_str_substr(VALUE str, long beg, long len)
+    pub fn rb_str_substr(str: Value, beg: c_long, len: c_long) -> Value;
+    // VALUE
+    // rb_str_scrub(VALUE str, VALUE repl)
+    pub fn rb_str_scrub(str: Value, repl: Value) -> Value;
+    #[cfg(ruby_gte_3_0)]
+    // VALUE
+    // rb_str_to_interned_str(VALUE str)
+    pub fn rb_str_to_interned_str(str: Value) -> Value;
+    #[cfg(ruby_gte_3_0)]
+    // VALUE
+    // rb_enc_interned_str(const char *ptr, long len, rb_encoding *enc)
+    pub fn rb_enc_interned_str(ptr: *const c_char, len: c_long, enc: EncodingType) -> Value;
+    // size_t
+    // rb_str_capacity(VALUE str)
+    pub fn rb_str_capacity(str: Value) -> size_t;
+    // void
+    // rb_str_modify(VALUE str)
+    pub fn rb_str_modify(str: Value);
+    // void
+    // rb_str_modify_expand(VALUE str, long expand)
+    pub fn rb_str_modify_expand(str: Value, expand: c_long);
+    // void
+    // rb_str_set_len(VALUE str, long len)
+    pub fn rb_str_set_len(str: Value, len: c_long);
 }
 
 unsafe fn rstring_and_flags(value: Value) -> (*const RString, InternalValue) {
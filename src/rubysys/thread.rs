@@ -1,4 +1,4 @@
-use super::types::{c_int, c_void, CallbackPtr, Value};
+use super::types::{c_int, c_void, CallbackPtr, Id, Value};
 
 #[cfg(unix)]
 use super::types::RawFd;
@@ -139,6 +139,10 @@ extern "C" {
     // rb_thread_call_with_gvl(void *(*func)(void *), void *data1)
     pub fn rb_thread_call_with_gvl(func: CallbackPtr, args: *const c_void) -> *mut c_void;
 
+    // void
+    // rb_thread_check_ints(void)
+    pub fn rb_thread_check_ints();
+
     // VALUE
     // rb_thread_create(VALUE (*fn)(ANYARGS), void *arg)
     pub fn rb_thread_create(
@@ -146,14 +150,49 @@ extern "C" {
         data: *mut c_void,
     ) -> Value;
 
+    // VALUE
+    // rb_thread_current(void)
+    pub fn rb_thread_current() -> Value;
+
+    // VALUE
+    // rb_thread_kill(VALUE thread)
+    pub fn rb_thread_kill(thread: Value) -> Value;
+
+    // VALUE
+    // rb_thread_local_aref(VALUE thread, ID key)
+    pub fn rb_thread_local_aref(thread: Value, key: Id) -> Value;
+
+    // VALUE
+    // rb_thread_local_aset(VALUE thread, ID key, VALUE val)
+    pub fn rb_thread_local_aset(thread: Value, key: Id, value: Value) -> Value;
+
+    // VALUE
+    // rb_thread_main(void)
+    pub fn rb_thread_main() -> Value;
+
+    // void
+    // rb_thread_schedule(void)
+    pub fn rb_thread_schedule();
+
     // void
     // rb_thread_wait_fd(int fd)
     #[cfg(unix)]
     pub fn rb_thread_wait_fd(fd: RawFd);
 
+    // This function can be called from any native thread, including threads
+    // which were not created by Ruby.
+    //
+    // int
+    // ruby_thread_has_gvl_p(void)
+    pub fn ruby_thread_has_gvl_p() -> c_int;
+
     // This function can be called in blocking region.
     //
     // int
     // rb_thread_interrupted(VALUE thval)
     pub fn rb_thread_interrupted(thread: Value) -> c_int;
+
+    // VALUE
+    // rb_thread_wakeup(VALUE thread)
+    pub fn rb_thread_wakeup(thread: Value) -> Value;
 }
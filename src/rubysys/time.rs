@@ -0,0 +1,20 @@
+use super::types::{c_int, Value};
+
+pub use libc::timespec;
+
+// Passing `RUBY_TIME_OFFSET_LOCALTIME` as `offset` creates a time in the local timezone.
+pub const RUBY_TIME_OFFSET_LOCALTIME: c_int = c_int::MAX;
+// Passing `RUBY_TIME_OFFSET_UTC` as `offset` creates a time in UTC.
+pub const RUBY_TIME_OFFSET_UTC: c_int = c_int::MAX - 1;
+
+extern "C" {
+    // VALUE
+    // rb_time_timespec_new(const struct timespec *ts, int offset)
+    pub fn rb_time_timespec_new(ts: *const timespec, offset: c_int) -> Value;
+    // struct timespec
+    // rb_time_timespec(VALUE time)
+    pub fn rb_time_timespec(time: Value) -> timespec;
+    // VALUE
+    // rb_time_utc_offset(VALUE time)
+    pub fn rb_time_utc_offset(time: Value) -> Value;
+}
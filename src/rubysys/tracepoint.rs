@@ -0,0 +1,75 @@
+use super::types::{c_void, Value};
+
+// rb_event_flag_t
+pub type EventFlag = u32;
+// rb_trace_arg_t *
+pub type TraceArgPtr = *mut c_void;
+
+pub const RUBY_EVENT_NONE: EventFlag = 0x0000;
+pub const RUBY_EVENT_LINE: EventFlag = 0x0001;
+pub const RUBY_EVENT_CLASS: EventFlag = 0x0002;
+pub const RUBY_EVENT_END: EventFlag = 0x0004;
+pub const RUBY_EVENT_CALL: EventFlag = 0x0008;
+pub const RUBY_EVENT_RETURN: EventFlag = 0x0010;
+pub const RUBY_EVENT_C_CALL: EventFlag = 0x0020;
+pub const RUBY_EVENT_C_RETURN: EventFlag = 0x0040;
+pub const RUBY_EVENT_RAISE: EventFlag = 0x0080;
+pub const RUBY_EVENT_B_CALL: EventFlag = 0x0100;
+pub const RUBY_EVENT_B_RETURN: EventFlag = 0x0200;
+pub const RUBY_EVENT_THREAD_BEGIN: EventFlag = 0x0400;
+pub const RUBY_EVENT_THREAD_END: EventFlag = 0x0800;
+pub const RUBY_EVENT_FIBER_SWITCH: EventFlag = 0x1000;
+
+pub const RUBY_INTERNAL_EVENT_GC_START: EventFlag = 0x0040_0000;
+pub const RUBY_INTERNAL_EVENT_GC_END_MARK: EventFlag = 0x0080_0000;
+pub const RUBY_INTERNAL_EVENT_GC_END_SWEEP: EventFlag = 0x0100_0000;
+pub const RUBY_INTERNAL_EVENT_GC_ENTER: EventFlag = 0x0200_0000;
+pub const RUBY_INTERNAL_EVENT_GC_EXIT: EventFlag = 0x0400_0000;
+
+extern "C" {
+    // VALUE
+    // rb_tracepoint_disable(VALUE tpval)
+    pub fn rb_tracepoint_disable(tpval: Value) -> Value;
+    // VALUE
+    // rb_tracepoint_enable(VALUE tpval)
+    pub fn rb_tracepoint_enable(tpval: Value) -> Value;
+    // VALUE
+    // rb_tracepoint_enabled_p(VALUE tpval)
+    pub fn rb_tracepoint_enabled_p(tpval: Value) -> Value;
+    // VALUE
+    // rb_tracepoint_new(VALUE target_thread_not_supported_yet, rb_event_flag_t events,
+    //                   void (*func)(VALUE, void *), void *data)
+    pub fn rb_tracepoint_new(
+        target_thread: Value,
+        events: EventFlag,
+        func: extern "C" fn(Value, *mut c_void),
+        data: *mut c_void,
+    ) -> Value;
+    // VALUE
+    // rb_tracearg_defined_class(rb_trace_arg_t *trace_arg)
+    pub fn rb_tracearg_defined_class(trace_arg: TraceArgPtr) -> Value;
+    // rb_event_flag_t
+    // rb_tracearg_event_flag(rb_trace_arg_t *trace_arg)
+    pub fn rb_tracearg_event_flag(trace_arg: TraceArgPtr) -> EventFlag;
+    // rb_trace_arg_t *
+    // rb_tracearg_from_tracepoint(VALUE tpval)
+    pub fn rb_tracearg_from_tracepoint(tpval: Value) -> TraceArgPtr;
+    // VALUE
+    // rb_tracearg_lineno(rb_trace_arg_t *trace_arg)
+    pub fn rb_tracearg_lineno(trace_arg: TraceArgPtr) -> Value;
+    // VALUE
+    // rb_tracearg_method_id(rb_trace_arg_t *trace_arg)
+    pub fn rb_tracearg_method_id(trace_arg: TraceArgPtr) -> Value;
+    // VALUE
+    // rb_tracearg_path(rb_trace_arg_t *trace_arg)
+    pub fn rb_tracearg_path(trace_arg: TraceArgPtr) -> Value;
+    // VALUE
+    // rb_tracearg_raised_exception(rb_trace_arg_t *trace_arg)
+    pub fn rb_tracearg_raised_exception(trace_arg: TraceArgPtr) -> Value;
+    // VALUE
+    // rb_tracearg_return_value(rb_trace_arg_t *trace_arg)
+    pub fn rb_tracearg_return_value(trace_arg: TraceArgPtr) -> Value;
+    // VALUE
+    // rb_tracearg_self(rb_trace_arg_t *trace_arg)
+    pub fn rb_tracearg_self(trace_arg: TraceArgPtr) -> Value;
+}
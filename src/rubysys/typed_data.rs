@@ -1,4 +1,8 @@
-use super::types::{c_char, c_int, c_void, Value};
+use super::types::{c_char, c_int, c_void, InternalValue, Value};
+
+// RUBY_FL_SHAREABLE, frozen objects of this type can be shared between Ractors
+#[cfg(ruby_gte_3_0)]
+pub const RUBY_TYPED_FROZEN_SHAREABLE: InternalValue = 1 << 8;
 
 extern "C" {
     // void *
@@ -54,10 +54,31 @@ extern "C" {
     // VALUE
     // rb_protect(VALUE (* proc) (VALUE), VALUE data, int *pstate)
     pub fn rb_protect(func: CallbackPtr, args: *const c_void, state: *mut c_int) -> Value;
+    // void
+    // rb_jump_tag(int state)
+    pub fn rb_jump_tag(state: c_int) -> !;
     // VALUE
     // rb_funcallv(VALUE recv, ID mid, int argc, const VALUE *argv)
     pub fn rb_funcallv(receiver: Value, method: Id, argc: Argc, argv: *const Value) -> Value;
     // VALUE
+    // rb_funcall_with_block(VALUE recv, ID mid, int argc, const VALUE *argv, VALUE procval)
+    pub fn rb_funcall_with_block(
+        receiver: Value,
+        method: Id,
+        argc: Argc,
+        argv: *const Value,
+        procval: Value,
+    ) -> Value;
+    // VALUE
+    // rb_funcallv_kw(VALUE recv, ID mid, int argc, const VALUE *argv, int kw_splat)
+    pub fn rb_funcallv_kw(
+        receiver: Value,
+        method: Id,
+        argc: Argc,
+        argv: *const Value,
+        kw_splat: c_int,
+    ) -> Value;
+    // VALUE
     // rb_funcallv_public(VALUE recv, ID mid, int argc, const VALUE *argv)
     pub fn rb_funcallv_public(receiver: Value, method: Id, argc: Argc, argv: *const Value)
         -> Value;
@@ -5,7 +5,7 @@ use crate::{
     },
     rubysys::rproc::{rb_obj_is_method, rb_obj_is_proc},
     types::{c_char, c_int, c_void, Argc, InternalValue, Value},
-    AnyObject, Boolean, Object,
+    AnyException, AnyObject, Boolean, Object, VM,
 };
 
 use std::{
@@ -124,7 +124,24 @@ pub fn inmost_rb_object(klass: &str) -> Value {
     klass.split("::").fold(object.into(), const_get)
 }
 
+// Runs `func` under `rb_protect`, returning the raised exception as `Err`
+pub(crate) fn protect<F>(func: F) -> Result<AnyObject, AnyException>
+where
+    F: FnMut() -> AnyObject,
+{
+    VM::protect(func).map_err(|_| {
+        let output = VM::error_info().unwrap();
+
+        // error cleanup
+        VM::clear_error_info();
+
+        output
+    })
+}
+
 pub mod callback_call {
+    use std::ops::ControlFlow;
+
     use crate::types::{st_retval, CallbackMutPtr};
 
     pub fn no_parameters<F: FnMut() -> R, R>(ptr: CallbackMutPtr) -> R {
@@ -137,15 +154,16 @@ pub mod callback_call {
         unsafe { (*f)(a) }
     }
 
-    pub fn hash_foreach_callback<F: FnMut(A, B), A, B>(
+    pub fn hash_foreach_callback<F: FnMut(A, B) -> ControlFlow<()>, A, B>(
         a: A,
         b: B,
         ptr: CallbackMutPtr,
     ) -> st_retval {
         let f = ptr as *mut F;
-        unsafe {
-            (*f)(a, b);
+
+        match unsafe { (*f)(a, b) } {
+            ControlFlow::Continue(()) => st_retval::Continue,
+            ControlFlow::Break(()) => st_retval::Stop,
         }
-        st_retval::Continue
     }
 }